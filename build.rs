@@ -0,0 +1,153 @@
+//! Turns the plain text word lists in `src/words/<set>/` into rust constants.
+//!
+//! Each set has a `solutions.txt` (in the order they are played), an `acceptable.txt`
+//! (every accepted guess, including the solutions), an optional `alphabet.txt`
+//! and an optional `frequency.txt` (lines of `<word> <count>`).
+//! The build fails if any of the lists are invalid.
+
+use std::{collections::HashSet, env, fmt::Write, fs, path::Path};
+
+const WORD_SETS: &[&str] = &["nytimes", "original", "german", "spanish", "french"];
+const ENGLISH_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for name in WORD_SETS {
+        let dir = Path::new("src/words").join(name);
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let code = word_set(&dir);
+        fs::write(Path::new(&out_dir).join(format!("{}.rs", name)), code).unwrap();
+    }
+}
+
+fn word_set(dir: &Path) -> String {
+    let alphabet_path = dir.join("alphabet.txt");
+    let alphabet = if alphabet_path.exists() {
+        read(&alphabet_path).trim().to_owned()
+    } else {
+        ENGLISH_ALPHABET.to_owned()
+    };
+
+    let solutions = word_list(&dir.join("solutions.txt"), &alphabet);
+    let acceptable = word_list(&dir.join("acceptable.txt"), &alphabet);
+
+    let accepted: HashSet<String> = acceptable.iter().cloned().collect();
+    for word in &solutions {
+        if !accepted.contains(word) {
+            panic!(
+                "{}: solution {:?} is missing from acceptable.txt",
+                dir.display(),
+                word
+            );
+        }
+    }
+
+    let mut sorted = solutions.clone();
+    sorted.sort_unstable();
+
+    let solution_set: HashSet<&str> = solutions.iter().map(|w| &**w).collect();
+    let mut acceptable: Vec<String> = acceptable
+        .into_iter()
+        .filter(|w| !solution_set.contains(&**w))
+        .collect();
+    acceptable.sort_unstable();
+
+    let frequency_path = dir.join("frequency.txt");
+    let frequency = if frequency_path.exists() {
+        frequency_list(&frequency_path, &accepted)
+    } else {
+        Vec::new()
+    };
+
+    let mut code = String::new();
+    writeln!(code, "/// The letters that words can be made from").unwrap();
+    writeln!(code, "pub const ALPHABET: &str = {:?};", alphabet).unwrap();
+    writeln!(code, "/// List of words that can be the final guess value").unwrap();
+    writeln!(code, "pub const FINAL: &[&str] = &{:?};", solutions).unwrap();
+    writeln!(code, "/// [`FINAL`], sorted").unwrap();
+    writeln!(code, "pub const SORTED: &[&str] = &{:?};", sorted).unwrap();
+    writeln!(
+        code,
+        "/// List of words that are acceptable guesses, excluding [`FINAL`]"
+    )
+    .unwrap();
+    writeln!(code, "pub const ACCEPT: &[&str] = &{:?};", acceptable).unwrap();
+    writeln!(code, "/// How often words are used, sorted by word").unwrap();
+    writeln!(
+        code,
+        "pub const FREQUENCY: &[(&str, u64)] = &{:?};",
+        frequency
+    )
+    .unwrap();
+    code
+}
+
+/// Reads a list of words, one per line. Empty lines and anything after a `#` are ignored
+fn word_list(path: &Path, alphabet: &str) -> Vec<String> {
+    let list = read(path);
+    let mut seen = HashSet::new();
+    let mut words = Vec::new();
+
+    for (i, line) in list.lines().enumerate() {
+        let word = line.split('#').next().unwrap_or_default().trim();
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().count() != 5 || !word.chars().all(|c| alphabet.contains(c)) {
+            panic!(
+                "{}:{}: {:?} is not 5 letters from {:?}",
+                path.display(),
+                i + 1,
+                word,
+                alphabet
+            );
+        }
+        if !seen.insert(word) {
+            panic!("{}:{}: duplicate word {:?}", path.display(), i + 1, word);
+        }
+        words.push(word.to_owned());
+    }
+
+    words
+}
+
+/// Reads a list of `<word> <count>` lines, sorted by word.
+/// Empty lines and anything after a `#` are ignored
+fn frequency_list(path: &Path, accepted: &HashSet<String>) -> Vec<(String, u64)> {
+    let list = read(path);
+    let mut seen = HashSet::new();
+    let mut counts = Vec::new();
+
+    for (i, line) in list.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let (word, count) = match parts[..] {
+            [] => continue,
+            [word, count] => match count.parse::<u64>() {
+                Ok(count) => (word, count),
+                Err(_) => panic!("{}:{}: invalid count {:?}", path.display(), i + 1, count),
+            },
+            _ => panic!("{}:{}: expected a word and a count", path.display(), i + 1),
+        };
+        if !accepted.contains(word) {
+            panic!(
+                "{}:{}: {:?} is not in acceptable.txt",
+                path.display(),
+                i + 1,
+                word
+            );
+        }
+        if !seen.insert(word) {
+            panic!("{}:{}: duplicate word {:?}", path.display(), i + 1, word);
+        }
+        counts.push((word.to_owned(), count));
+    }
+
+    counts.sort_unstable();
+    counts
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e))
+}
@@ -0,0 +1,129 @@
+//! Reports on how well each guess in a game was played
+
+use std::{cmp::Ordering, fmt};
+
+use eyre::{ensure, eyre, Result};
+
+use crate::{
+    solver::{allowed, partition, pattern, remaining, Entropy},
+    state::State,
+    Matches,
+};
+
+/// How a single guess was played
+#[derive(Clone, Debug)]
+pub struct GuessReport {
+    pub guess: String,
+    pub matches: Matches,
+    /// How many solutions were possible before the guess
+    pub before: usize,
+    /// How many solutions were possible after the guess
+    pub after: usize,
+    /// How much information the guess was expected to give, compared to
+    /// the best guess. From `0` to `100`
+    pub skill: f64,
+    /// How the solutions left compare to the other ways the guess could have gone.
+    /// `50` is an average (or certain) outcome, `100` is the best possible. From `0` to `100`
+    pub luck: f64,
+    /// The guess the [`Entropy`] solver would have made instead
+    pub best: &'static str,
+}
+
+impl fmt::Display for GuessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} -> {} left, skill {:.0}, luck {:.0}, best {}",
+            self.guess.to_uppercase(),
+            self.matches,
+            self.before,
+            self.after,
+            self.skill,
+            self.luck,
+            self.best.to_uppercase(),
+        )
+    }
+}
+
+/// Analyses every guess made in a game, optionally following hard mode
+///
+/// ```
+/// use cl_wordle::{analysis, state::State, words::WordSetBuf};
+/// let word_set = WordSetBuf::parse("cigar\nrebut\nsissy\nhumph\nawake\nblush", "").unwrap().leak();
+/// let mut state = State::new("cigar".to_owned(), word_set);
+/// state.guess("sissy", false).unwrap();
+/// state.guess("cigar", false).unwrap();
+///
+/// let report = analysis::analyze(&state, false).unwrap();
+/// assert_eq!((report[0].before, report[0].after), (6, 1));
+/// assert_eq!(report[0].best, "rebut");
+/// assert!(report[0].skill < 100.0);
+/// // only `cigar` was left, so it was the best guess, and winning was certain
+/// assert_eq!((report[1].skill, report[1].luck), (100.0, 50.0));
+///
+/// // `humph` isn't a solution, so nothing fits after the first guess
+/// let word_set = WordSetBuf::parse("cigar\nrebut", "humph").unwrap().leak();
+/// let mut state = State::new("humph".to_owned(), word_set);
+/// state.guess("cigar", false).unwrap();
+/// state.guess("humph", false).unwrap();
+/// assert!(analysis::analyze(&state, false).is_err());
+/// ```
+///
+/// # Errors
+/// If the solution isn't in the word set's solutions list, so at some point no
+/// solutions are left to compare the guesses with, or if no guess is allowed
+pub fn analyze(state: &State, hard: bool) -> Result<Vec<GuessReport>> {
+    let word_set = state.word_set();
+    let mut replay = State::new(state.solution().to_owned(), word_set);
+
+    let mut reports = Vec::new();
+    for (guess, matches) in state.guesses() {
+        let candidates = remaining(word_set, replay.guesses());
+        ensure!(
+            !candidates.is_empty(),
+            "no solutions were left before {:?}, so it can't be analyzed",
+            guess
+        );
+        let ranked = Entropy::rank_guesses(allowed(&replay, hard), &candidates);
+        let (best, best_entropy) = *ranked
+            .first()
+            .ok_or_else(|| eyre!("no guesses were allowed before {:?}", guess))?;
+
+        let skill = if best_entropy > 0.0 {
+            100.0 * Entropy::entropy(guess, &candidates) / best_entropy
+        } else if candidates.contains(&guess) {
+            100.0
+        } else {
+            0.0
+        };
+
+        // compare the solutions left with every other way the guess could have gone.
+        // winning leaves nothing, and ties count as half
+        let left = |p: usize, n: usize| if p == 0 { 0 } else { n };
+        let counts = partition(guess, &candidates);
+        let actual = left(pattern(matches), counts[pattern(matches)]);
+        let luck: f64 = counts
+            .iter()
+            .enumerate()
+            .map(|(p, &n)| match left(p, n).cmp(&actual) {
+                Ordering::Greater => n as f64,
+                Ordering::Equal => n as f64 / 2.0,
+                Ordering::Less => 0.0,
+            })
+            .sum();
+
+        reports.push(GuessReport {
+            guess: guess.to_owned(),
+            matches,
+            before: candidates.len(),
+            after: counts[pattern(matches)],
+            skill,
+            luck: 100.0 * luck / candidates.len() as f64,
+            best,
+        });
+
+        // the guess was already accepted once, so it will be again
+        let _ = replay.guess(guess, false);
+    }
+    Ok(reports)
+}
@@ -1,6 +1,15 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{ArgEnum, Parser, Subcommand};
+use cl_wordle::{
+    solver::{Entropy, Metric, Minimax, Solver},
+    words::{self, WordSet},
+};
 use time::macros::format_description;
 
+#[cfg(feature = "rand")]
+use cl_wordle::difficulty::Difficulty;
+
 #[derive(Parser)]
 #[clap(name = "wordle", author, version)]
 pub struct App {
@@ -11,7 +20,21 @@ pub struct App {
     /// This disables the TUI and any enriched experience
     pub no_tui: bool,
 
+    #[clap(long, arg_enum)]
+    /// Play over stdin and stdout, without the TUI
+    ///
+    /// `plain` reads one guess per line and prints the matches as emoji.
+    /// `json` reads and writes JSON lines, for bots.
+    /// See the `cl_wordle::protocol` docs for the messages
+    pub protocol: Option<Protocol>,
+
     #[clap(long)]
+    /// Play a daily puzzle again, even if you have already finished it
+    ///
+    /// Replays aren't saved, and don't count towards your stats or history
+    pub replay: bool,
+
+    #[clap(long, global = true)]
     /// Enables hard mode
     ///
     /// Hard mode adds the restriction that you must only make
@@ -19,29 +42,191 @@ pub struct App {
     pub hard: bool,
 
     #[clap(long)]
-    /// Enables Original mode
+    /// Only accept common words as guesses
+    ///
+    /// Words are rated using --frequencies if given.
+    /// Otherwise only words from the solutions list are accepted
+    pub beginner: bool,
+
+    #[clap(long, requires = "beginner")]
+    /// How common a guess must be in beginner mode, from 0 to 1. Defaults to 0.5
+    pub min_commonness: Option<f64>,
+
+    #[clap(long, arg_enum, default_value = "en")]
+    /// The language of the word set to play with
     ///
     /// The NYTimes bought and are now hosting their own
     /// version of wordle. It's mostly the same
     /// but features a ever so slightly adapted word list.
-    /// This disables using the new list and instead uses the original
-    pub original: bool,
+    /// `en-original` uses the original list instead
+    pub lang: Lang,
+
+    #[clap(long, requires = "acceptable")]
+    /// Load the solutions word list from a file
+    ///
+    /// The file should contain one 5 letter word per line.
+    /// Requires --acceptable to also be given.
+    /// The start date is taken from --lang
+    pub solutions: Option<PathBuf>,
+
+    #[clap(long, requires = "solutions")]
+    /// Load the acceptable guesses word list from a file
+    ///
+    /// The file should contain one 5 letter word per line.
+    /// Requires --solutions to also be given
+    pub acceptable: Option<PathBuf>,
+
+    #[clap(long)]
+    /// Load a schedule of solution overrides from a file
+    ///
+    /// Each line should contain a day number or a year-month-day date,
+    /// followed by the solution for that day
+    pub schedule: Option<PathBuf>,
+
+    #[clap(long)]
+    /// Load word frequencies from a file
+    ///
+    /// Each line should contain a word followed by how many times it is used.
+    /// Words not in the word lists are skipped
+    pub frequencies: Option<PathBuf>,
 
     #[clap(subcommand)]
     pub game_mode: Option<GameMode>,
 }
 
+#[derive(ArgEnum, Clone, Copy)]
+pub enum Protocol {
+    /// A guess per line in, emoji matches out
+    Plain,
+    /// A JSON object per line in and out
+    Json,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum Lang {
+    /// English, NYTimes word list
+    En,
+    /// English, original word list
+    EnOriginal,
+    #[cfg(feature = "lang-de")]
+    /// German
+    De,
+    #[cfg(feature = "lang-es")]
+    /// Spanish
+    Es,
+    #[cfg(feature = "lang-fr")]
+    /// French
+    Fr,
+}
+
+impl Lang {
+    /// The name of the language, as given to --lang
+    pub fn name(self) -> &'static str {
+        self.to_possible_value()
+            .expect("every language can be picked")
+            .get_name()
+    }
+
+    pub fn word_set(self) -> WordSet<'static> {
+        match self {
+            Lang::En => words::NYTIMES,
+            Lang::EnOriginal => words::ORIGINAL,
+            #[cfg(feature = "lang-de")]
+            Lang::De => words::GERMAN,
+            #[cfg(feature = "lang-es")]
+            Lang::Es => words::SPANISH,
+            #[cfg(feature = "lang-fr")]
+            Lang::Fr => words::FRENCH,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum GameMode {
     /// Play with a custom word
     Custom(Custom),
+    /// Play a custom word, from the puzzle code shown on its share card
+    Code(Code),
     /// Play the specified day's wordle
     Day(Day),
     /// Play the specified date's wordle
     Date(Date),
     #[cfg(feature = "rand")]
-    /// Play a random day
-    Random,
+    /// Play a random word
+    Random(Random),
+    /// Work out a solver's guesses for every solution, and how many guesses it needs
+    Tree(Tree),
+    /// Get suggestions while playing somewhere else, by entering your guesses and their colours
+    Assist(Assist),
+    /// Rate the skill and luck of each guess in a finished game
+    Analyze(Analyze),
+    /// Play every solution with a solver, and report how many guesses it needs
+    Bench(Bench),
+    /// Play bots against each other, using the JSON protocol
+    Tournament(Tournament),
+    /// Rank every word as the first guess of a game
+    Openers(Openers),
+    /// Show your stats for the daily games, played on their day
+    Stats,
+    /// List every finished game, or export them as CSV or JSON
+    History(History),
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum SolverKind {
+    /// Picks the guess that gives the most information on average
+    Entropy,
+    /// Picks the guess that leaves the fewest solutions in the worst case
+    Minimax,
+}
+
+impl SolverKind {
+    pub fn solver(self, opener: Option<String>) -> Box<dyn Solver + Send + Sync> {
+        match (self, opener) {
+            (SolverKind::Entropy, None) => Box::new(Entropy::default()),
+            (SolverKind::Entropy, Some(opener)) => Box::new(Entropy::with_opener(opener)),
+            (SolverKind::Minimax, None) => Box::new(Minimax::default()),
+            (SolverKind::Minimax, Some(opener)) => Box::new(Minimax::with_opener(opener)),
+        }
+    }
+
+    /// Ranks every guess in the word set, best first, with a description of its score
+    pub fn rank(
+        self,
+        word_set: WordSet<'static>,
+        candidates: &[&str],
+    ) -> Vec<(&'static str, String)> {
+        match self {
+            SolverKind::Entropy => Entropy::rank(word_set, candidates)
+                .into_iter()
+                .map(|(guess, bits)| (guess, format!("{:.2} bits", bits)))
+                .collect(),
+            SolverKind::Minimax => Minimax::rank(word_set, candidates)
+                .into_iter()
+                .map(|(guess, worst)| (guess, format!("at most {} left", worst)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum MetricKind {
+    /// The most information on average
+    Entropy,
+    /// The fewest solutions left on average
+    ExpectedRemaining,
+    /// The fewest solutions left in the worst case
+    WorstCase,
+}
+
+impl MetricKind {
+    pub fn metric(self) -> Metric {
+        match self {
+            MetricKind::Entropy => Metric::Entropy,
+            MetricKind::ExpectedRemaining => Metric::ExpectedRemaining,
+            MetricKind::WorstCase => Metric::WorstCase,
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -49,6 +234,27 @@ pub struct Custom {
     pub word: String,
 }
 
+#[derive(Parser)]
+#[cfg(feature = "rand")]
+pub struct Random {
+    #[clap(long, parse(try_from_str = parse_seed))]
+    /// Replay a random game, from the code shown on its share card
+    ///
+    /// The code is the seed written in base32, such as `#15NM7`, with or without the `#`.
+    /// It is not a decimal number, so `--seed 42` is a different seed from 42
+    pub seed: Option<u64>,
+
+    #[clap(long, conflicts_with = "seed", possible_values = ["easy", "medium", "hard"])]
+    /// Only pick solutions with this difficulty
+    pub difficulty: Option<Difficulty>,
+}
+
+#[derive(Parser)]
+pub struct Code {
+    #[clap(parse(try_from_str = parse_code))]
+    pub code: u64,
+}
+
 #[derive(Parser)]
 pub struct Day {
     pub day: usize,
@@ -65,3 +271,177 @@ fn parse_date(input: &str) -> Result<time::Date, time::error::Parse> {
     let description = format_description!("[year]-[month padding:none]-[day padding:none]");
     time::Date::parse(input, description)
 }
+
+fn parse_seed(input: &str) -> Result<u64, &'static str> {
+    cl_wordle::code::decode(input.trim_start_matches('#')).ok_or("invalid seed code")
+}
+
+fn parse_code(input: &str) -> Result<u64, &'static str> {
+    cl_wordle::code::decode(input.trim_start_matches('#')).ok_or("invalid puzzle code")
+}
+
+#[derive(Parser)]
+pub struct Tree {
+    #[clap(long, arg_enum, default_value = "entropy")]
+    /// The solver to make the guesses
+    pub solver: SolverKind,
+
+    #[clap(long)]
+    /// The first guess to make. Defaults to the solver's choice
+    pub opener: Option<String>,
+
+    #[clap(long)]
+    /// Write the tree to this file as JSON
+    pub json: Option<PathBuf>,
+
+    #[clap(long)]
+    /// Write the tree to this file as a Graphviz DOT graph
+    pub dot: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+pub struct Assist {
+    #[clap(long, arg_enum, default_value = "entropy")]
+    /// The solver to rank the suggestions
+    pub solver: SolverKind,
+
+    #[clap(long, default_value = "5")]
+    /// How many suggestions to show
+    pub top: usize,
+}
+
+#[derive(Parser)]
+pub struct Analyze {
+    #[clap(required = true)]
+    /// The guesses that were made, in order
+    pub guesses: Vec<String>,
+
+    #[clap(long, conflicts_with = "word")]
+    /// The day that was played. Defaults to today
+    pub day: Option<usize>,
+
+    #[clap(long)]
+    /// The solution, for games that weren't a daily
+    pub word: Option<String>,
+}
+
+#[derive(Parser)]
+pub struct Bench {
+    #[clap(long, arg_enum, default_value = "entropy")]
+    /// The solver to make the guesses
+    pub solver: SolverKind,
+
+    #[clap(long)]
+    /// The first guess to make. Defaults to the solver's choice
+    pub opener: Option<String>,
+
+    #[clap(long)]
+    /// How many threads to play the games on. Defaults to the number of CPUs
+    pub threads: Option<usize>,
+}
+
+#[derive(Parser)]
+pub struct Tournament {
+    #[clap(required = true)]
+    /// The commands to start each bot, such as "python3 bot.py"
+    ///
+    /// Commands are split like a shell would, so paths with spaces can be quoted.
+    /// Each bot is started once per game, and plays it with `--protocol json`.
+    /// The start message only gives the game number, not the day or seed.
+    /// Unlike `--protocol json`, the game doesn't carry on after an error:
+    /// a bot loses the game if it makes an invalid guess, exits, or is too slow
+    pub bots: Vec<String>,
+
+    #[clap(long, default_value = "100")]
+    /// How many games each bot plays
+    pub games: usize,
+
+    #[clap(long)]
+    /// Play the daily games in order from this day. Defaults to the first day
+    pub first_day: Option<usize>,
+
+    #[clap(long, conflicts_with = "first-day", parse(try_from_str = parse_seed))]
+    /// Play random games instead, with seeds in order from this seed code
+    ///
+    /// The code is written as on a share card, such as `#15NM7`, not as a decimal number
+    pub seed: Option<u64>,
+
+    #[clap(long, default_value = "5000")]
+    /// How long a bot can take to make each guess, in milliseconds
+    pub timeout: u64,
+}
+
+#[derive(Parser)]
+pub struct Openers {
+    #[clap(long, arg_enum, default_value = "entropy")]
+    /// How to score the guesses
+    pub metric: MetricKind,
+
+    #[clap(long, default_value = "10")]
+    /// How many openers to show
+    pub top: usize,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum GameKind {
+    Daily,
+    Random,
+    Custom,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum HardMode {
+    Normal,
+    Hard,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum Outcome {
+    Won,
+    Lost,
+}
+
+#[derive(ArgEnum, Clone, Copy)]
+pub enum HistoryFormat {
+    /// One game per line
+    List,
+    /// A header row, then one row per game
+    Csv,
+    /// An array of objects
+    Json,
+}
+
+#[derive(Parser)]
+pub struct History {
+    #[clap(long, parse(try_from_str = parse_date))]
+    /// Only games finished on or after this date, year-month-day
+    pub since: Option<time::Date>,
+
+    #[clap(long, parse(try_from_str = parse_date))]
+    /// Only games finished on or before this date, year-month-day
+    pub until: Option<time::Date>,
+
+    #[clap(long, arg_enum)]
+    /// Only games of this kind
+    pub kind: Option<GameKind>,
+
+    #[clap(long)]
+    /// Only games played with this word set, such as a --lang name
+    pub word_set: Option<String>,
+
+    #[clap(long, arg_enum)]
+    /// Only games played in this mode
+    pub mode: Option<HardMode>,
+
+    #[clap(long, arg_enum)]
+    /// Only games with this outcome
+    pub result: Option<Outcome>,
+
+    #[clap(long, arg_enum, default_value = "list")]
+    /// How to write the games
+    pub format: HistoryFormat,
+
+    #[clap(long)]
+    /// Write the games to this file instead of stdout
+    pub output: Option<PathBuf>,
+}
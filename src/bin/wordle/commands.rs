@@ -0,0 +1,8 @@
+pub mod analyze;
+pub mod assist;
+pub mod bench;
+pub mod history;
+pub mod openers;
+pub mod stats;
+pub mod tournament;
+pub mod tree;
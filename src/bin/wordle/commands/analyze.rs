@@ -0,0 +1,29 @@
+use cl_wordle::{analysis, game::Game, words::WordSet};
+use eyre::{eyre, Result};
+
+use crate::args::Analyze;
+
+pub fn run(args: Analyze, word_set: WordSet<'static>, hard: bool) -> Result<()> {
+    let mut game = match (args.word, args.day) {
+        (Some(word), _) => Game::custom(word, word_set)?,
+        (None, Some(day)) => Game::from_day(day, word_set),
+        (None, None) => Game::new(word_set)?,
+    };
+    if hard {
+        game.hard_mode();
+    }
+
+    for guess in &args.guesses {
+        if game.game_over().is_some() {
+            return Err(eyre!("the game was already over before {:?}", guess));
+        }
+        game.guess(&guess.to_lowercase())
+            .map_err(|e| eyre!("{:?} is not a valid guess: {:?}", guess, e))?;
+    }
+
+    println!("Wordle {}", game.game_type());
+    for report in analysis::analyze(&game, hard)? {
+        println!("{}", report);
+    }
+    Ok(())
+}
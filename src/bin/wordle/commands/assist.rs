@@ -0,0 +1,130 @@
+use std::io::{stdin, stdout, Write};
+
+use cl_wordle::{
+    diff,
+    solver::{expected_remaining, is_possible},
+    words::WordSet,
+    Match, Matches,
+};
+use eyre::{bail, Result};
+
+use crate::args::Assist;
+
+/// Candidates are listed out once there are this few left
+const LIST_CANDIDATES: usize = 10;
+
+pub fn run(args: Assist, word_set: WordSet<'static>, hard: bool) -> Result<()> {
+    println!("Enter each guess followed by the colours you got, eg `crane gy..g`");
+    println!("g = green, y = yellow, . = grey. Enter an empty line to stop");
+    if hard {
+        println!("Hard mode: suggestions keep every green letter");
+    }
+
+    let mut candidates = word_set.solutions.to_vec();
+    suggest(&args, word_set, &candidates, None);
+
+    let stdin = stdin();
+    let mut line = String::new();
+    loop {
+        print!("> ");
+        stdout().flush()?;
+        line.clear();
+        if stdin.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let (guess, matches) = match parse_entry(line.trim(), word_set) {
+            Ok(entry) => entry,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+        if matches.win() {
+            println!("Solved!");
+            return Ok(());
+        }
+        if !is_possible(&guess, matches) {
+            println!("Those colours are impossible: a letter can't be yellow after the same letter is grey");
+            continue;
+        }
+
+        let remaining: Vec<&str> = candidates
+            .iter()
+            .copied()
+            .filter(|solution| diff(&guess, solution) == matches)
+            .collect();
+        if remaining.is_empty() {
+            println!("No solutions match those colours, check them and try again");
+            continue;
+        }
+
+        candidates = remaining;
+        let last = (guess, matches);
+        suggest(&args, word_set, &candidates, Some(&last).filter(|_| hard));
+    }
+}
+
+fn parse_entry(line: &str, word_set: WordSet<'static>) -> Result<(String, Matches)> {
+    let mut parts = line.split_whitespace();
+    let (guess, colours) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(guess), Some(colours), None) => (guess.to_lowercase(), colours),
+        _ => bail!("Expected a guess followed by its colours, eg `crane gy..g`"),
+    };
+    if !word_set.valid(&guess) {
+        bail!("{:?} is not in the word list", guess);
+    }
+    Ok((guess, colours.parse()?))
+}
+
+/// Prints the remaining candidates and the best guesses to make next.
+/// If `hard` is given, only guesses that keep its green letters are suggested
+fn suggest(
+    args: &Assist,
+    word_set: WordSet<'static>,
+    candidates: &[&str],
+    hard: Option<&(String, Matches)>,
+) {
+    if candidates.len() == 1 {
+        println!("The solution is {}", candidates[0].to_uppercase());
+        return;
+    }
+
+    println!("{} solutions left", candidates.len());
+    if candidates.len() <= LIST_CANDIDATES {
+        let list: Vec<String> = candidates.iter().map(|w| w.to_uppercase()).collect();
+        println!("  {}", list.join(" "));
+    }
+
+    let keeps_greens = |guess: &str| match hard {
+        None => true,
+        Some((last, matches)) => last
+            .chars()
+            .zip(guess.chars())
+            .zip(matches.iter())
+            .all(|((a, b), m)| *m != Match::Exact || a == b),
+    };
+
+    println!("Suggestions:");
+    let ranked = args.solver.rank(word_set, candidates);
+    for (i, (guess, score)) in ranked
+        .into_iter()
+        .filter(|(guess, _)| keeps_greens(guess))
+        .take(args.top)
+        .enumerate()
+    {
+        let possible = if candidates.contains(&guess) {
+            " (could be the solution)"
+        } else {
+            ""
+        };
+        println!(
+            "  {}. {} - {}, {:.1} left on average{}",
+            i + 1,
+            guess.to_uppercase(),
+            score,
+            expected_remaining(guess, candidates),
+            possible,
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::{sync::Arc, thread, time::Instant};
+
+use cl_wordle::{solver::Benchmark, words::WordSet};
+use eyre::Result;
+
+use crate::args::Bench;
+
+pub fn run(args: Bench, word_set: WordSet<'static>, hard: bool) -> Result<()> {
+    if let Some(opener) = &args.opener {
+        eyre::ensure!(
+            word_set.valid(opener),
+            "{:?} is not in the word list",
+            opener
+        );
+    }
+
+    let threads = match args.threads {
+        Some(threads) => threads,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let solver = Arc::from(args.solver.solver(args.opener));
+
+    let start = Instant::now();
+    let bench = Benchmark::run(solver, word_set, hard, threads);
+
+    println!("solutions: {}", word_set.solutions.len());
+    for (guesses, count) in bench.guess_counts.iter().enumerate().skip(1) {
+        println!("{}: {}", guesses, count);
+    }
+    println!("mean guesses: {:.4}", bench.average_guesses());
+    println!("worst case: {}", bench.max_guesses());
+    println!("failures: {}", bench.failures.len());
+    for failure in &bench.failures {
+        println!("  {}", failure);
+    }
+    println!("time: {:.2?}", start.elapsed());
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::fs;
+
+use cl_wordle::{
+    game::GameType,
+    history::{History, Record},
+};
+use eyre::{Result, WrapErr};
+
+use crate::{
+    args::{self, GameKind, HardMode, HistoryFormat, Outcome},
+    data,
+};
+
+pub fn run(args: args::History) -> Result<()> {
+    let mut history = History::load(data::path("history.txt")?)?;
+    history.records.retain(|record| matches(&args, record));
+
+    let output = match args.format {
+        HistoryFormat::List => {
+            if history.records.is_empty() && args.output.is_none() {
+                println!("No finished games found");
+                return Ok(());
+            }
+            history
+                .records
+                .iter()
+                .map(|record| format!("{}\n", record))
+                .collect()
+        }
+        HistoryFormat::Csv => history.to_csv(),
+        HistoryFormat::Json => history.to_json() + "\n",
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, output).wrap_err_with(|| format!("could not write {}", path.display()))
+        }
+        None => {
+            print!("{}", output);
+            Ok(())
+        }
+    }
+}
+
+/// Determines if a record passes every filter given
+fn matches(args: &args::History, record: &Record) -> bool {
+    let kind = match (args.kind, record.game_type) {
+        (None, _) => true,
+        (Some(GameKind::Daily), GameType::Daily(_))
+        | (Some(GameKind::Random), GameType::Random(_))
+        | (Some(GameKind::Custom), GameType::Custom(_)) => true,
+        (Some(_), _) => false,
+    };
+    let mode = match args.mode {
+        None => true,
+        Some(HardMode::Normal) => !record.hard_mode,
+        Some(HardMode::Hard) => record.hard_mode,
+    };
+    let result = match args.result {
+        None => true,
+        Some(Outcome::Won) => record.won(),
+        Some(Outcome::Lost) => !record.won(),
+    };
+
+    kind && mode
+        && result
+        && args.since.map_or(true, |since| record.played >= since)
+        && args.until.map_or(true, |until| record.played <= until)
+        && args
+            .word_set
+            .as_ref()
+            .map_or(true, |word_set| &record.word_set == word_set)
+}
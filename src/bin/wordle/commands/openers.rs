@@ -0,0 +1,79 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cl_wordle::{
+    solver::{rank_openers, Metric},
+    words::WordSet,
+};
+use eyre::{Result, WrapErr};
+
+use crate::args::Openers;
+
+pub fn run(args: Openers, word_set: WordSet<'static>) -> Result<()> {
+    let metric = args.metric.metric();
+    let path = cache_path(word_set, metric);
+
+    let openers = match path.as_ref().and_then(|path| load(path)) {
+        Some(openers) => openers,
+        None => {
+            eprintln!("Scoring every opener. This only has to be done once for each word set");
+            let openers: Vec<(String, f64)> = rank_openers(word_set, metric)
+                .into_iter()
+                .map(|(guess, score)| (guess.to_owned(), score))
+                .collect();
+            if let Some(path) = path {
+                save(&path, &openers)
+                    .wrap_err_with(|| format!("could not write {}", path.display()))?;
+            }
+            openers
+        }
+    };
+
+    for (i, (guess, score)) in openers.iter().take(args.top).enumerate() {
+        let score = match metric {
+            Metric::Entropy => format!("{:.4} bits", score),
+            Metric::ExpectedRemaining => format!("{:.2} left on average", score),
+            Metric::WorstCase => format!("at most {} left", score),
+        };
+        println!("{}. {} {}", i + 1, guess, score);
+    }
+    Ok(())
+}
+
+/// Where the scores are cached, keyed by the [`WordSet::version`]
+fn cache_path(word_set: WordSet<'_>, metric: Metric) -> Option<PathBuf> {
+    let metric = match metric {
+        Metric::Entropy => "entropy",
+        Metric::ExpectedRemaining => "expected-remaining",
+        Metric::WorstCase => "worst-case",
+    };
+    let file = format!("openers-{:016x}-{}.txt", word_set.version(), metric);
+    Some(dirs::cache_dir()?.join("cl-wordle").join(file))
+}
+
+/// Loads cached scores, written as lines of `<word> <score>`.
+/// Returns `None` if there is no cache, or it can't be read
+fn load(path: &Path) -> Option<Vec<(String, f64)>> {
+    let cache = fs::read_to_string(path).ok()?;
+    cache
+        .lines()
+        .map(|line| {
+            let (guess, score) = line.split_once(' ')?;
+            Some((guess.to_owned(), score.parse().ok()?))
+        })
+        .collect()
+}
+
+fn save(path: &Path, openers: &[(String, f64)]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let cache: String = openers
+        .iter()
+        .map(|(guess, score)| format!("{} {}\n", guess, score))
+        .collect();
+    fs::write(path, cache)?;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use cl_wordle::stats::StatsBook;
+use eyre::Result;
+
+use crate::data;
+
+pub fn run() -> Result<()> {
+    let book = StatsBook::load(data::path("stats.txt")?)?;
+
+    let mut empty = true;
+    for (word_set, hard, stats) in book.iter() {
+        if !empty {
+            println!();
+        }
+        empty = false;
+
+        let mode = if hard { " (hard mode)" } else { "" };
+        println!("{}{}", word_set, mode);
+        println!("{}", stats);
+    }
+    if empty {
+        println!("No daily games have been finished yet");
+    }
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    process::{ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use cl_wordle::{
+    game::Game,
+    protocol::{Request, Response},
+    words::WordSet,
+};
+use eyre::{Result, WrapErr};
+
+use crate::args::Tournament;
+
+/// How a single game went for a bot
+enum Outcome {
+    Solved(usize),
+    Lost,
+    /// Made a guess that wasn't allowed, or sent a line that couldn't be understood
+    Invalid,
+    Timeout,
+    /// Exited or closed its output before the game was over
+    Crashed,
+}
+
+#[derive(Default)]
+struct Score {
+    solved: usize,
+    guesses: usize,
+    lost: usize,
+    invalid: usize,
+    timeout: usize,
+    crashed: usize,
+}
+
+impl Score {
+    fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Solved(guesses) => {
+                self.solved += 1;
+                self.guesses += guesses;
+            }
+            Outcome::Lost => self.lost += 1,
+            Outcome::Invalid => self.invalid += 1,
+            Outcome::Timeout => self.timeout += 1,
+            Outcome::Crashed => self.crashed += 1,
+        }
+    }
+
+    fn failures(&self) -> usize {
+        self.lost + self.invalid + self.timeout + self.crashed
+    }
+
+    /// The average guesses for the games that were solved, if any were
+    fn average_guesses(&self) -> Option<f64> {
+        (self.solved > 0).then(|| self.guesses as f64 / self.solved as f64)
+    }
+}
+
+pub fn run(args: Tournament, word_set: WordSet<'static>, hard: bool) -> Result<()> {
+    let timeout = Duration::from_millis(args.timeout);
+    let new_game = |i: usize| {
+        let mut game = match args.seed {
+            Some(seed) => Game::from_seed(seed.wrapping_add(i as u64), word_set),
+            None => Game::from_day(args.first_day.unwrap_or_default() + i, word_set),
+        };
+        if hard {
+            game.hard_mode();
+        }
+        game
+    };
+
+    let mut scores = Vec::new();
+    for bot in &args.bots {
+        let command = shell_words::split(bot)
+            .wrap_err_with(|| format!("could not split the bot command {:?}", bot))?;
+        eyre::ensure!(!command.is_empty(), "bot commands can't be empty");
+
+        let mut score = Score::default();
+        for i in 0..args.games {
+            // bots only get told the game number, as the day or seed would give the solution away
+            let name = (i + 1).to_string();
+            score.add(play(&command, new_game(i), &name, timeout)?);
+        }
+        scores.push((bot, score));
+    }
+
+    // fewest failures first, then fewest guesses
+    scores.sort_by(|(_, a), (_, b)| {
+        let average = |score: &Score| score.average_guesses().unwrap_or(f64::INFINITY);
+        a.failures()
+            .cmp(&b.failures())
+            .then(average(a).partial_cmp(&average(b)).unwrap())
+    });
+
+    let width = args
+        .bots
+        .iter()
+        .map(|bot| bot.len())
+        .max()
+        .unwrap_or(0)
+        .max(3);
+    println!(
+        "{:<4} {:<width$} {:>8} {:>7} {:>6} {:>5} {:>7} {:>7} {:>7}",
+        "rank", "bot", "average", "solved", "failed", "lost", "invalid", "timeout", "crashed",
+    );
+    for (rank, (bot, score)) in scores.iter().enumerate() {
+        println!(
+            "{:<4} {:<width$} {:>8} {:>7} {:>6} {:>5} {:>7} {:>7} {:>7}",
+            rank + 1,
+            bot,
+            score
+                .average_guesses()
+                .map_or_else(|| "-".to_owned(), |average| format!("{:.4}", average)),
+            score.solved,
+            score.failures(),
+            score.lost,
+            score.invalid,
+            score.timeout,
+            score.crashed,
+        );
+    }
+    Ok(())
+}
+
+/// Starts the bot and plays a game with it
+fn play(command: &[String], game: Game, name: &str, timeout: Duration) -> Result<Outcome> {
+    let mut child = Command::new(&command[0])
+        .args(&command[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .wrap_err_with(|| format!("could not start {:?}", shell_words::join(command)))?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let stdout = child.stdout.take().expect("stdout is piped");
+
+    // read on another thread, so waiting for a line can time out
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let outcome = referee(game, name, &mut stdin, &rx, timeout).unwrap_or(Outcome::Crashed);
+    drop(stdin);
+    let _ = child.kill();
+    let _ = child.wait();
+    Ok(outcome)
+}
+
+/// Plays the game over the bot's stdin and stdout.
+/// Unlike `--protocol json`, the game doesn't carry on after an error:
+/// a bot forfeits the game if it makes an invalid guess or sends a bad request
+fn referee(
+    mut game: Game,
+    name: &str,
+    stdin: &mut ChildStdin,
+    lines: &Receiver<io::Result<String>>,
+    timeout: Duration,
+) -> io::Result<Outcome> {
+    writeln!(stdin, "{}", Response::Start(&game, name))?;
+    loop {
+        let line = match lines.recv_timeout(timeout) {
+            Ok(line) => line?,
+            Err(RecvTimeoutError::Timeout) => return Ok(Outcome::Timeout),
+            Err(RecvTimeoutError::Disconnected) => return Ok(Outcome::Crashed),
+        };
+
+        let guess = match line.parse() {
+            Ok(Request::Guess(guess)) => guess,
+            Err(report) => {
+                writeln!(stdin, "{}", Response::BadRequest(&report))?;
+                return Ok(Outcome::Invalid);
+            }
+        };
+
+        match game.guess(&guess) {
+            Err(e) => {
+                writeln!(stdin, "{}", Response::Error(&e, &guess))?;
+                return Ok(Outcome::Invalid);
+            }
+            Ok(matches) => {
+                let guess_number = game.guesses().count();
+                let result = Response::Result {
+                    guess: &guess,
+                    matches,
+                    guess_number,
+                };
+                writeln!(stdin, "{}", result)?;
+                if let Some(over) = game.game_over() {
+                    writeln!(stdin, "{}", Response::GameOver(&game))?;
+                    return Ok(if over.is_win() {
+                        Outcome::Solved(guess_number)
+                    } else {
+                        Outcome::Lost
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::fs;
+
+use cl_wordle::{solver::DecisionTree, words::WordSet};
+use eyre::{Result, WrapErr};
+
+use crate::args::Tree;
+
+pub fn run(args: Tree, word_set: WordSet<'static>, hard: bool) -> Result<()> {
+    if let Some(opener) = &args.opener {
+        eyre::ensure!(
+            word_set.valid(opener),
+            "{:?} is not in the word list",
+            opener
+        );
+    }
+
+    let solver = args.solver.solver(args.opener);
+    let tree = DecisionTree::build(&*solver, word_set, hard)?;
+
+    println!("opener: {}", tree.guess);
+    println!("solutions: {}", tree.solutions());
+    println!("max guesses: {}", tree.max_guesses());
+    println!("average guesses: {:.4}", tree.average_guesses());
+    for (guesses, count) in tree.guess_counts().iter().enumerate().skip(1) {
+        println!("{}: {}", guesses, count);
+    }
+
+    if let Some(path) = args.json {
+        fs::write(&path, tree.to_json())
+            .wrap_err_with(|| format!("could not write {}", path.display()))?;
+    }
+    if let Some(path) = args.dot {
+        fs::write(&path, tree.to_dot())
+            .wrap_err_with(|| format!("could not write {}", path.display()))?;
+    }
+    Ok(())
+}
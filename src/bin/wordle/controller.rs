@@ -1,3 +1,24 @@
+use cl_wordle::game::Game;
+
 pub mod cli;
 #[cfg(feature = "tui")]
 pub mod tui;
+
+/// How a game was left
+pub enum Exit {
+    /// The game is over
+    GameOver(Game),
+    /// The player quit before the game was over
+    Quit(Game),
+}
+
+/// Formats the time left until the next daily game, as `hours:minutes:seconds`
+pub fn countdown(left: time::Duration) -> String {
+    let seconds = left.whole_seconds().max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
@@ -1,30 +1,84 @@
-use std::io::stdin;
+use std::io::{stdin, stdout, Write};
 
-use eyre::Result;
+use eyre::{eyre, Result};
 
-use cl_wordle::game::{Game, GameShare};
+use cl_wordle::{
+    game::Game,
+    protocol::{Request, Response},
+};
+
+use super::Exit;
+use crate::{args::Protocol, data};
 
 pub struct Controller {
     game: Game,
+    protocol: Protocol,
+    /// The word set to record the stats for, if they are kept for this game
+    stats: Option<String>,
 }
 
 impl Controller {
-    pub fn new(game: Game) -> Self {
-        Self { game }
+    pub fn new(game: Game, protocol: Protocol, stats: Option<String>) -> Self {
+        Self {
+            game,
+            protocol,
+            stats,
+        }
     }
 
-    pub fn run(mut self) -> Result<GameShare> {
+    /// Plays the game over stdin and stdout
+    pub fn run(mut self) -> Result<Exit> {
         let stdin = stdin();
+        let mut stdout = stdout();
         let mut line = String::with_capacity(6);
 
+        // a finished daily game was resumed
+        if self.game.game_over().is_some() {
+            println!("You've already finished this puzzle. Use --replay to play it again");
+            return Ok(Exit::GameOver(self.game));
+        }
+
+        if let Protocol::Json = self.protocol {
+            let name = self.game.game_type().to_string();
+            writeln!(stdout, "{}", Response::Start(&self.game, &name))?;
+        }
+
         let game_over = loop {
+            stdout.flush()?;
             line.clear();
-            stdin.read_line(&mut line)?;
+            if stdin.read_line(&mut line)? == 0 {
+                return match self.protocol {
+                    Protocol::Plain => Ok(Exit::Quit(self.game)),
+                    Protocol::Json => Err(eyre!("input ended before the game was over")),
+                };
+            }
 
-            match self.game.guess(line.trim_end()) {
-                Err(_) => println!("INVALID"),
-                Ok(m) => {
-                    println!("{}", m);
+            let guess = match self.protocol {
+                Protocol::Plain => line.trim_end().to_owned(),
+                Protocol::Json => match line.parse() {
+                    Ok(Request::Guess(guess)) => guess,
+                    Err(report) => {
+                        writeln!(stdout, "{}", Response::BadRequest(&report))?;
+                        continue;
+                    }
+                },
+            };
+
+            match (self.game.guess(&guess), self.protocol) {
+                (Err(_), Protocol::Plain) => writeln!(stdout, "INVALID")?,
+                (Err(e), Protocol::Json) => writeln!(stdout, "{}", Response::Error(&e, &guess))?,
+                (Ok(matches), protocol) => {
+                    match protocol {
+                        Protocol::Plain => writeln!(stdout, "{}", matches)?,
+                        Protocol::Json => {
+                            let result = Response::Result {
+                                guess: &guess,
+                                matches,
+                                guess_number: self.game.guesses().count(),
+                            };
+                            writeln!(stdout, "{}", result)?
+                        }
+                    }
                     if let Some(win) = self.game.game_over() {
                         break win;
                     }
@@ -32,13 +86,18 @@ impl Controller {
             }
         };
 
-        if game_over.is_lose() {
-            println!(
-                "GAME OVER - '{}'",
-                self.game.solution().to_ascii_uppercase()
-            );
+        if let Some(word_set) = &self.stats {
+            data::record_stats(&self.game, word_set)?;
         }
 
-        Ok(self.game.share())
+        match self.protocol {
+            Protocol::Plain => {
+                if game_over.is_lose() {
+                    println!("GAME OVER - '{}'", self.game.solution().to_uppercase());
+                }
+            }
+            Protocol::Json => writeln!(stdout, "{}", Response::GameOver(&self.game))?,
+        }
+        Ok(Exit::GameOver(self.game))
     }
 }
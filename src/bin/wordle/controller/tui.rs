@@ -1,10 +1,6 @@
 use std::io::{self, Write};
 
-use cl_wordle::{
-    game::{Game, GameShare},
-    state::GuessError,
-    Match,
-};
+use cl_wordle::{analysis, game::Game, state::GuessError, stats::Stats, Match};
 use crossterm::{
     cursor,
     event::{self, KeyCode, KeyModifiers},
@@ -18,82 +14,111 @@ use owo_colors::{
 };
 
 mod guess;
+mod hints;
 mod keyboard;
 mod letters;
 mod terminal;
 
-use self::{guess::Guesses, keyboard::Keyboard, letters::WordMatch, terminal::Terminal};
+use super::{countdown, Exit};
+use crate::data;
+
+use self::{
+    guess::Guesses, hints::Hints, keyboard::Keyboard, letters::WordMatch, terminal::Terminal,
+};
 
 pub struct Controller {
     game: Game,
     keyboard: Keyboard,
     stdout: Terminal,
     word: String,
+    /// The hint panel, if it is open
+    hints: Option<Hints>,
+    /// The word set to record the stats for, if they are kept for this game
+    stats: Option<String>,
 }
 
 impl Controller {
-    pub fn new(game: Game) -> Result<Self> {
+    pub fn new(game: Game, stats: Option<String>) -> Result<Self> {
+        // a resumed game already has guesses
+        let mut keyboard = Keyboard::new(game.word_set().alphabet);
+        for (guess, matches) in game.guesses() {
+            keyboard.push(guess, matches);
+        }
         Ok(Self {
-            game,
-            keyboard: Keyboard::default(),
+            keyboard,
             stdout: Terminal::new()?,
             word: String::with_capacity(5),
+            hints: None,
+            stats,
+            game,
         })
     }
 
-    pub fn run(mut self) -> Result<Option<GameShare>> {
+    pub fn run(mut self) -> Result<Exit> {
         self.display_window()?;
 
-        let game_over = loop {
-            self.stdout.flush()?;
-            if let event::Event::Key(key) = event::read()? {
-                match (key.code, key.modifiers) {
-                    (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
-                        self.word.clear();
-                        self.display_window()?;
-                    }
-                    (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
-                        return Ok(None);
-                    },
-                    (KeyCode::Enter, _) if self.word.len() == 5 => match self.guess() {
-                        Ok(()) => {
+        let already_over = self.game.game_over().is_some();
+        let game_over = match self.game.game_over() {
+            // a finished daily game was resumed
+            Some(game_over) => game_over,
+            None => loop {
+                self.stdout.flush()?;
+                if let event::Event::Key(key) = event::read()? {
+                    match (key.code, key.modifiers) {
+                        (KeyCode::Char('w'), KeyModifiers::CONTROL) => {
+                            self.word.clear();
                             self.display_window()?;
+                        }
+                        (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                            return Ok(Exit::Quit(self.game));
+                        },
+                        (KeyCode::Enter, _) if self.word.chars().count() == 5 => match self.guess() {
+                            Ok(()) => {
+                                self.display_window()?;
 
-                            if let Some(win) = self.game.game_over() {
-                                break win;
+                                if let Some(win) = self.game.game_over() {
+                                    break win;
+                                }
+                            }
+                            Err(_) => {
+                                self.display_window()?;
+                                self.display_invalid()?;
                             }
+                        },
+                        (KeyCode::Char(','), _) => {
+                            self.keyboard.shuffle();
+                            self.display_window()?;
                         }
-                        Err(_) => {
+                        (KeyCode::Char('?'), _) => {
+                            self.toggle_hints();
                             self.display_window()?;
-                            self.display_invalid()?;
                         }
-                    },
-                    (KeyCode::Char(','), _) => {
-                        self.keyboard.shuffle();
-                        self.display_window()?;
-                    }
-                    (KeyCode::Char(c), _) if c.is_ascii_alphabetic() && self.word.len() < 5 => {
-                        let c = c.to_ascii_lowercase();
-                        write!(self.stdout, "{}", c.to_ascii_uppercase())?;
-                        self.word.push(c);
-                    }
-                    (KeyCode::Backspace, _) => {
-                        self.word.pop();
-                        if self.word.len() == 4 {
-                            write!(
-                                self.stdout,
-                                "{bol}{word} {back}",
-                                back = cursor::MoveLeft(1),
-                                bol = cursor::MoveLeft(5),
-                                word = self.word.to_ascii_uppercase()
-                                )?;
-                        } else {
-                            write!(self.stdout, "{back} {back}", back = cursor::MoveLeft(1))?;
+                        (KeyCode::Char(c), _)
+                            if self.keyboard.contains(lowercase(c))
+                                && self.word.chars().count() < 5 =>
+                        {
+                            let c = lowercase(c);
+                            write!(self.stdout, "{}", c.to_uppercase())?;
+                            self.word.push(c);
                         }
+                        (KeyCode::Backspace, _) => {
+                            self.word.pop();
+                            if self.word.chars().count() == 4 {
+                                write!(
+                                    self.stdout,
+                                    "{bol}{word} {back}",
+                                    back = cursor::MoveLeft(1),
+                                    bol = cursor::MoveLeft(5),
+                                    word = self.word.to_uppercase()
+                                    )?;
+                            } else {
+                                write!(self.stdout, "{back} {back}", back = cursor::MoveLeft(1))?;
+                            }
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
-            }
+            },
         };
 
         if game_over.is_lose() {
@@ -101,27 +126,48 @@ impl Controller {
         }
 
         execute!(self.stdout, cursor::Hide)?;
+        if let Some(word_set) = &self.stats {
+            // a resumed game was already recorded when it was finished
+            let stats = if already_over {
+                data::stats(&self.game, word_set)?
+            } else {
+                data::record_stats(&self.game, word_set)?
+            };
+            self.display_stats(&stats)?;
+        }
+        self.display_game_over_help()?;
 
         loop {
             self.stdout.flush()?;
             if let event::Event::Key(key) = event::read()? {
                 match key.code {
                     KeyCode::Enter | KeyCode::Esc => break,
+                    KeyCode::Char('a') => self.display_analysis()?,
                     _ => {}
                 }
             }
         }
 
-        Ok(Some(self.game.share()))
+        Ok(Exit::GameOver(self.game))
     }
 
     fn guess(&mut self) -> Result<(), GuessError> {
-        let matches = self.game.guess(&*self.word)?;
-        self.keyboard.push(&*self.word, matches);
+        let matches = self.game.guess(&self.word)?;
+        self.keyboard.push(&self.word, matches);
         self.word.clear();
+        if self.hints.is_some() {
+            self.hints = Some(Hints::new(&self.game));
+        }
         Ok(())
     }
 
+    fn toggle_hints(&mut self) {
+        if self.hints.take().is_none() {
+            self.game.mark_assisted();
+            self.hints = Some(Hints::new(&self.game));
+        }
+    }
+
     pub fn write_final_solution(&mut self) -> io::Result<()> {
         write!(self.stdout, "{}", cursor::MoveDown(1))?;
         write!(
@@ -132,12 +178,76 @@ impl Controller {
         write!(self.stdout, "{}", cursor::MoveTo(0, 10))
     }
 
+    /// Shows the stats in place of the hint panel
+    fn display_stats(&mut self, stats: &Stats) -> io::Result<()> {
+        write!(self.stdout, "{}", cursor::SavePosition)?;
+        for (i, line) in stats.to_string().lines().enumerate() {
+            write!(
+                self.stdout,
+                "{start}{clear}{line}",
+                start = cursor::MoveTo(24, 2 + i as u16),
+                clear = Clear(ClearType::UntilNewLine),
+            )?;
+        }
+        write!(self.stdout, "{}", cursor::RestorePosition)
+    }
+
+    fn display_game_over_help(&mut self) -> io::Result<()> {
+        let (_width, height) =
+            crossterm::terminal::size().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let next = match self.game.next_daily_in() {
+            Some(left) => format!("Next puzzle in {}. ", countdown(left)),
+            None => String::new(),
+        };
+        write!(
+            self.stdout,
+            "{save}{bottom_left}{clear}> {next}Press ENTER to exit. Press 'a' to analyze your guesses.{restore}",
+            save = cursor::SavePosition,
+            bottom_left = cursor::MoveTo(0, height - 1),
+            clear = Clear(ClearType::CurrentLine),
+            restore = cursor::RestorePosition,
+        )
+    }
+
+    fn display_analysis(&mut self) -> io::Result<()> {
+        write!(
+            self.stdout,
+            "{clear_all}{top_left}Analyzing...",
+            clear_all = Clear(ClearType::All),
+            top_left = cursor::MoveTo(0, 0),
+        )?;
+        self.stdout.flush()?;
+
+        let reports = analysis::analyze(&self.game, self.game.is_hard_mode());
+        write!(
+            self.stdout,
+            "{clear_all}{top_left}Wordle {game_type}",
+            clear_all = Clear(ClearType::All),
+            top_left = cursor::MoveTo(0, 0),
+            game_type = self.game.game_type(),
+        )?;
+        match reports {
+            Ok(reports) => {
+                for (i, report) in reports.iter().enumerate() {
+                    write!(self.stdout, "{}{}", cursor::MoveTo(0, 2 + i as u16), report)?;
+                }
+            }
+            Err(e) => write!(
+                self.stdout,
+                "{}Can't analyze this game: {}",
+                cursor::MoveTo(0, 2),
+                e
+            )?,
+        }
+        self.display_game_over_help()
+    }
+
     fn display_invalid(&mut self) -> io::Result<()> {
         write!(
             self.stdout,
             "{back}{invalid}",
             back = cursor::MoveLeft(5),
-            invalid = self.word.to_ascii_uppercase().fg::<White>().bg::<Red>()
+            invalid = self.word.to_uppercase().fg::<White>().bg::<Red>()
         )
     }
 
@@ -147,28 +257,43 @@ impl Controller {
 
         write!(
             self.stdout,
-            "{clear_all}{bottom_left}> Press ESC to exit. Press ',' to shuffle the keyboard.",
+            "{clear_all}{bottom_left}> Press ESC to exit. Press ',' to shuffle the keyboard. Press '?' for hints.",
             clear_all = Clear(ClearType::All),
             bottom_left = cursor::MoveTo(0, height - 1),
         )?;
 
         write!(
             self.stdout,
-            "{top_left}Wordle {game_type} {current_guess}/{total_guesses}{hard_mode}{down}{keyboard}{state}{word}",
+            "{top_left}Wordle {game_type} {current_guess}/{total_guesses}{hard_mode} {language}{date}{down}{keyboard}{state}{word}",
             top_left = cursor::MoveTo(0, 0),
             game_type = self.game.game_type(),
+            language = self.game.word_set().language,
+            date = self.game.date().map(|date| format!(" ({})", date)).unwrap_or_default(),
             current_guess = self.game.current_guess(),
             total_guesses = self.game.max_guess(),
             hard_mode = self.game.hard_mode_indicator(),
             down = cursor::MoveTo(0, 2),
             keyboard = self.keyboard,
             state = Guesses::from(&*self.game),
-            word = self.word.to_ascii_uppercase(),
+            word = self.word.to_uppercase(),
         )?;
-        if self.game.guess(&*self.word).is_err() {
+        if let Some(hints) = &self.hints {
+            write!(self.stdout, "{}", hints)?;
+        }
+        if self.game.check(&self.word).is_err() {
             self.display_invalid()?;
         }
 
         Ok(())
     }
 }
+
+/// Lowercases a single letter. Letters that don't lowercase
+/// to a single letter are returned unchanged
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
@@ -16,8 +16,8 @@ impl<'a> From<&'a State> for Guesses<'a> {
 impl<'a> fmt::Display for Guesses<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (w, Matches(m)) in self.0.guesses() {
-            for (b, m) in w.bytes().zip(m) {
-                write!(f, "{}", LetterMatch(b as char, m))?;
+            for (c, m) in w.chars().zip(m) {
+                write!(f, "{}", LetterMatch(c, m))?;
             }
             write!(f, "{}{}", cursor::MoveDown(1), cursor::MoveLeft(5))?;
         }
@@ -0,0 +1,60 @@
+use std::fmt::{self, Display};
+
+use cl_wordle::{
+    game::Game,
+    solver::{self, Entropy},
+};
+use crossterm::cursor;
+
+/// How many guesses to suggest
+const SUGGESTIONS: usize = 5;
+
+/// Side panel with the number of solutions left, and the best guesses to make next
+pub struct Hints {
+    remaining: usize,
+    /// Suggested guesses, with how many solutions they are expected to leave
+    suggestions: Vec<(&'static str, f64)>,
+}
+
+impl Hints {
+    pub fn new(game: &Game) -> Self {
+        let word_set = game.word_set();
+        let candidates = solver::remaining(word_set, game.guesses());
+        let suggestions = Entropy::rank(word_set, &candidates)
+            .into_iter()
+            .map(|(guess, _)| guess)
+            // follow hard mode and any other rules of the game
+            .filter(|guess| game.check(guess).is_ok())
+            .take(SUGGESTIONS)
+            .map(|guess| (guess, solver::expected_remaining(guess, &candidates)))
+            .collect();
+
+        Self {
+            remaining: candidates.len(),
+            suggestions,
+        }
+    }
+}
+
+impl Display for Hints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{save}{start}{remaining} left",
+            save = cursor::SavePosition,
+            start = cursor::MoveTo(24, 2),
+            remaining = self.remaining,
+        )?;
+
+        for (i, (guess, expected)) in self.suggestions.iter().enumerate() {
+            write!(
+                f,
+                "{start}{guess} {expected:.1}",
+                start = cursor::MoveTo(24, 3 + i as u16),
+                guess = guess.to_uppercase(),
+            )?;
+        }
+
+        write!(f, "{restore}", restore = cursor::RestorePosition)
+    }
+}
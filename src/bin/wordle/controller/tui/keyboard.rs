@@ -7,14 +7,32 @@ use rand::prelude::SliceRandom;
 use super::letters::LetterMatch;
 
 pub struct Keyboard {
-    arangement: Vec<u8>,
-    letters: [Option<Match>; 26],
+    alphabet: Vec<char>,
+    arangement: Vec<usize>,
+    letters: Vec<Option<Match>>,
 }
 
 impl Keyboard {
+    pub fn new(alphabet: &str) -> Self {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        Self {
+            arangement: (0..alphabet.len()).collect(),
+            letters: vec![None; alphabet.len()],
+            alphabet,
+        }
+    }
+
+    /// Determines if the given letter is on this keyboard
+    pub fn contains(&self, c: char) -> bool {
+        self.alphabet.contains(&c)
+    }
+
     pub fn push(&mut self, word: &str, matches: Matches) {
-        for (b, m) in word.bytes().zip(matches.0) {
-            let b = (b - b'a') as usize;
+        for (c, m) in word.chars().zip(matches.0) {
+            let b = match self.alphabet.iter().position(|&a| a == c) {
+                Some(b) => b,
+                None => continue,
+            };
             let m2 = &mut self.letters[b];
             *m2 = Some(match (m, *m2) {
                 (_, Some(Match::Exact)) | (Match::Exact, _) => Match::Exact,
@@ -27,7 +45,7 @@ impl Keyboard {
 
     /// sorts letters that are `Some(Wrong)` to the end of the list
     fn sort(&mut self) {
-        self.arangement.sort_by_key(|&b| self.letters[b as usize]);
+        self.arangement.sort_by_key(|&b| self.letters[b]);
     }
 
     pub fn shuffle(&mut self) {
@@ -35,22 +53,13 @@ impl Keyboard {
         let i = self
             .arangement
             .iter()
-            .position(|&b| self.letters[b as usize] == Some(Match::Wrong))
+            .position(|&b| self.letters[b] == Some(Match::Wrong))
             .unwrap_or(self.arangement.len());
         // shuffles the current valid range of letters
         self.arangement[..i].shuffle(&mut rand::thread_rng())
     }
 }
 
-impl Default for Keyboard {
-    fn default() -> Self {
-        Self {
-            arangement: (0..26).collect(),
-            letters: [None; 26],
-        }
-    }
-}
-
 impl Display for Keyboard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -60,17 +69,18 @@ impl Display for Keyboard {
             start = cursor::MoveTo(14, 2)
         )?;
 
-        for (i, &b) in self.arangement.iter().enumerate() {
-            if i == 7 || i == 14 || i == 21 {
+        for (i, row) in self.arangement.chunks(7).enumerate() {
+            if i > 0 {
                 write!(f, "{}{}", cursor::MoveDown(1), cursor::MoveLeft(7))?;
             }
-            if i == 21 {
-                write!(f, " ")?;
-            }
-            let c = (b + b'A') as char;
-            match self.letters[b as usize] {
-                Some(m) => write!(f, "{}", LetterMatch(c, m))?,
-                None => write!(f, "{}", c)?,
+            // center the final row
+            write!(f, "{:1$}", "", (7 - row.len()) / 2)?;
+            for &b in row {
+                let c = self.alphabet[b];
+                match self.letters[b] {
+                    Some(m) => write!(f, "{}", LetterMatch(c, m))?,
+                    None => write!(f, "{}", c.to_uppercase())?,
+                }
             }
         }
 
@@ -9,14 +9,14 @@ use owo_colors::{
 pub struct LetterMatch(pub char, pub Match);
 impl fmt::Display for LetterMatch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        render(self.1, self.0.to_ascii_uppercase(), f)
+        render(self.1, self.0.to_uppercase(), f)
     }
 }
 
 pub struct WordMatch<'a>(pub &'a str, pub Match);
 impl<'a> fmt::Display for WordMatch<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        render(self.1, self.0.to_ascii_uppercase(), f)
+        render(self.1, self.0.to_uppercase(), f)
     }
 }
 
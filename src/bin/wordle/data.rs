@@ -0,0 +1,124 @@
+//! Files kept in the user's data directory between runs
+
+use std::path::PathBuf;
+
+use cl_wordle::{
+    game::{Game, GameType},
+    history::{History, Record},
+    save::{SavedGame, Saves},
+    stats::{Stats, StatsBook},
+};
+use eyre::{eyre, Result};
+
+/// Gets the path of a file in the data directory
+pub fn path(file: &str) -> Result<PathBuf> {
+    let dir = dirs::data_dir().ok_or_else(|| eyre!("could not find a data directory"))?;
+    Ok(dir.join("cl-wordle").join(file))
+}
+
+/// Gets the stats for the word set and mode of a game, without recording it
+#[cfg(feature = "tui")]
+pub fn stats(game: &Game, word_set: &str) -> Result<Stats> {
+    let book = StatsBook::load(path("stats.txt")?)?;
+    Ok(book
+        .get(word_set, game.is_hard_mode())
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Records a finished daily game in the stats for its word set,
+/// returning the updated stats
+pub fn record_stats(game: &Game, word_set: &str) -> Result<Stats> {
+    let path = path("stats.txt")?;
+    let mut book = StatsBook::load(&path)?;
+
+    let stats = book.entry(word_set, game.is_hard_mode());
+    let recorded = match game.game_type() {
+        GameType::Daily(day) => stats.record(day, game),
+        GameType::Random(_) | GameType::Custom(_) => false,
+    };
+    let stats = stats.clone();
+
+    if recorded {
+        book.save(&path)?;
+    }
+    Ok(stats)
+}
+
+/// Carries on from where this game was saved, if it was.
+///
+/// Saves that can't be read, or whose guesses are no longer allowed, such as
+/// after the word lists changed, are dropped with a warning, and the game starts afresh
+pub fn resume(game: Game, word_set: &str) -> Result<Game> {
+    let path = path("saves.txt")?;
+    let (mut saves, errors) = Saves::load_lossy(&path)?;
+    for e in &errors {
+        eprintln!("Warning: dropping a saved game that can't be read: {:#}", e);
+    }
+
+    let restored = match saves.get(word_set, game.game_type()) {
+        Some(saved) => saved.restore(game.word_set()),
+        None => return Ok(game),
+    };
+    match restored {
+        Ok(game) => Ok(game),
+        Err(e) => {
+            eprintln!(
+                "Warning: starting afresh, as the saved game can't be restored: {:#}",
+                e
+            );
+            saves.remove(word_set, game.game_type());
+            saves.save(&path)?;
+            Ok(game)
+        }
+    }
+}
+
+/// Saves an unfinished game, so it can be resumed the next time it is played
+pub fn save(game: &Game, word_set: &str) -> Result<()> {
+    let path = path("saves.txt")?;
+    let (mut saves, _) = Saves::load_lossy(&path)?;
+    prune(&mut saves, game, word_set);
+    saves.insert(SavedGame::new(game, word_set));
+    saves.save(&path)
+}
+
+/// Updates the save of a finished game. Daily games stay saved, so they are
+/// shown instead of being played again. Other games are removed, so they can be replayed
+pub fn finish(game: &Game, word_set: &str) -> Result<()> {
+    let path = path("saves.txt")?;
+    let (mut saves, _) = Saves::load_lossy(&path)?;
+    prune(&mut saves, game, word_set);
+    match game.game_type() {
+        GameType::Daily(_) => saves.insert(SavedGame::new(game, word_set)),
+        game_type @ (GameType::Random(_) | GameType::Custom(_)) => {
+            saves.remove(word_set, game_type);
+        }
+    }
+    saves.save(&path)
+}
+
+/// Drops the finished daily games of the word set from before this game's day,
+/// so the saves don't keep growing. Stats are only kept for today's daily,
+/// so only the latest finished daily needs to stay saved
+fn prune(saves: &mut Saves, game: &Game, word_set: &str) {
+    let today = match game.game_type() {
+        GameType::Daily(day) => day,
+        GameType::Random(_) | GameType::Custom(_) => return,
+    };
+    saves.retain(|saved| match saved.game_type {
+        GameType::Daily(day) if saved.word_set == word_set && day < today => saved
+            .restore(game.word_set())
+            .map_or(false, |saved| saved.game_over().is_none()),
+        _ => true,
+    });
+}
+
+/// Adds a finished game to the history, dated today
+pub fn record_history(game: &Game, word_set: &str) -> Result<()> {
+    let now = time::OffsetDateTime::now_local().unwrap_or_else(|_| time::OffsetDateTime::now_utc());
+    History::append(
+        path("history.txt")?,
+        &Record::new(game, word_set, now.date()),
+    )
+}
@@ -1,48 +1,186 @@
-use cl_wordle::game::Game;
+use cl_wordle::{
+    code,
+    game::{Game, GameType},
+    words::WordSetBuf,
+};
 use clap::Parser;
 
 mod args;
+mod commands;
 mod controller;
+mod data;
 
-use args::{App, GameMode};
-use controller::cli;
+use args::{App, GameMode, Lang, Protocol};
+use controller::{cli, Exit};
 
+#[cfg(feature = "rand")]
+use cl_wordle::difficulty::Ratings;
+#[cfg(feature = "rand")]
+use eyre::{ensure, eyre};
 #[cfg(feature = "rand")]
 use rand::Rng;
+#[cfg(feature = "rand")]
+use std::collections::HashSet;
 
 fn main() -> eyre::Result<()> {
     let app = App::parse();
 
-    let word_set = if app.original {
-        cl_wordle::words::ORIGINAL
+    let custom_lists = app.solutions.is_some();
+
+    let word_set = app.lang.word_set();
+    let word_set = match (app.solutions, app.acceptable) {
+        (Some(solutions), Some(acceptable)) => {
+            let mut loaded = WordSetBuf::load(solutions, acceptable)?;
+            loaded.language = word_set.language.to_owned();
+            loaded.date_offset = word_set.date_offset;
+            loaded.leak()
+        }
+        _ => word_set,
+    };
+
+    let word_set = match app.schedule {
+        Some(schedule) => {
+            let mut word_set = WordSetBuf::from(word_set);
+            word_set.load_schedule(schedule)?;
+            word_set.leak()
+        }
+        None => word_set,
+    };
+
+    let word_set = match app.frequencies {
+        Some(frequencies) => {
+            let mut word_set = WordSetBuf::from(word_set);
+            word_set.load_frequencies(frequencies)?;
+            word_set.leak()
+        }
+        None => word_set,
+    };
+
+    // stats and saves are kept apart for each word set
+    let word_set_name = if custom_lists {
+        format!("custom-{:016x}", word_set.version())
     } else {
-        cl_wordle::words::NYTIMES
+        app.lang.name().to_owned()
     };
+    // bots playing over the JSON protocol always start afresh, and don't count towards the stats
+    // or history. Neither do replays
+    let interactive = !matches!(app.protocol, Some(Protocol::Json));
+    let persistent = interactive && !app.replay;
 
-    let mut game = match app.game_mode {
+    let game = match app.game_mode {
         None => Game::new(word_set)?,
         Some(GameMode::Custom(custom)) => Game::custom(custom.word, word_set)?,
+        Some(GameMode::Code(code)) => Game::from_code(code.code, word_set)?,
         Some(GameMode::Day(day)) => Game::from_day(day.day, word_set),
         #[cfg(feature = "rand")]
-        Some(GameMode::Random) => Game::from_day(rand::thread_rng().gen(), word_set),
-        Some(GameMode::Date(date)) => Game::from_date(date.date, word_set),
+        Some(GameMode::Random(random)) => {
+            // keep generated seeds small, so the codes are short
+            let new_seed = || rand::thread_rng().gen_range(0..1 << 30);
+            match (random.seed, random.difficulty) {
+                (Some(seed), _) => Game::from_seed(seed, word_set),
+                (None, None) => Game::from_seed(new_seed(), word_set),
+                (None, Some(difficulty)) => {
+                    let ratings = Ratings::new(word_set);
+                    let band: HashSet<&str> = ratings.solutions(difficulty).collect();
+                    ensure!(!band.is_empty(), "there are no {} solutions to pick from", difficulty);
+
+                    // walk the seeds from a random start until one lands in the band, so
+                    // the game can still be replayed from its seed. Each band holds about
+                    // a third of the solutions, so this takes only a few tries
+                    let start = new_seed();
+                    (start..start + 1000)
+                        .map(|seed| Game::from_seed(seed, word_set))
+                        .find(|game| band.contains(game.solution()))
+                        .ok_or_else(|| eyre!("could not find a seed for a {} solution", difficulty))?
+                }
+            }
+        }
+        Some(GameMode::Date(date)) => Game::from_date(date.date, word_set)?,
+        Some(GameMode::Tree(tree)) => return commands::tree::run(tree, word_set, app.hard),
+        Some(GameMode::Assist(assist)) => return commands::assist::run(assist, word_set, app.hard),
+        Some(GameMode::Analyze(analyze)) => {
+            return commands::analyze::run(analyze, word_set, app.hard)
+        }
+        Some(GameMode::Bench(bench)) => return commands::bench::run(bench, word_set, app.hard),
+        Some(GameMode::Tournament(tournament)) => {
+            return commands::tournament::run(tournament, word_set, app.hard)
+        }
+        Some(GameMode::Openers(openers)) => return commands::openers::run(openers, word_set),
+        Some(GameMode::Stats) => return commands::stats::run(),
+        Some(GameMode::History(history)) => return commands::history::run(history),
+    };
+
+    let mut game = if persistent {
+        data::resume(game, &word_set_name)?
+    } else {
+        game
     };
+    let game_type = game.game_type();
 
-    if app.hard {
-        game.hard_mode();
+    // a resumed game keeps the rules it was started with, since its guesses were checked against them
+    if game.guesses().next().is_some() {
+        if app.hard && !game.is_hard_mode() {
+            eprintln!("Warning: this game was started in normal mode, so it can't switch to hard mode");
+        }
+    } else {
+        if app.hard {
+            game.hard_mode();
+        }
+        if app.beginner {
+            game.common_words_only(app.min_commonness.unwrap_or(0.5));
+        }
     }
 
+    // stats are only kept for today's game, so the days are recorded in order
+    let stats = (game.is_today() && persistent).then(|| word_set_name.clone());
+    // a finished daily that was resumed is only shown, so it's already in the history
+    let already_over = game.game_over().is_some();
+
     #[cfg(feature = "tui")]
-    let output = if app.no_tui {
-        Some(cli::Controller::new(game).run()?)
+    let output = if app.no_tui || app.protocol.is_some() {
+        cli::Controller::new(game, app.protocol.unwrap_or(Protocol::Plain), stats).run()?
     } else {
-        controller::tui::Controller::new(game)?.run()?
+        controller::tui::Controller::new(game, stats)?.run()?
     };
     #[cfg(not(feature = "tui"))]
-    let output = Some(cli::Controller::new(game).run()?);
+    let output =
+        cli::Controller::new(game, app.protocol.unwrap_or(Protocol::Plain), stats).run()?;
 
-    if let Some(share) = output {
-        println!("{}", share);
+    match output {
+        Exit::GameOver(game) => {
+            if persistent {
+                data::finish(&game, &word_set_name)?;
+            }
+            if persistent && !already_over {
+                data::record_history(&game, &word_set_name)?;
+            }
+            // the JSON protocol already sent the share card
+            if interactive {
+                let next = game.next_daily_in();
+                println!("{}", game.share());
+                if let Some(left) = next {
+                    println!("Next puzzle in {}", controller::countdown(left));
+                }
+            }
+        }
+        Exit::Quit(game) if persistent && game.guesses().next().is_some() => {
+            data::save(&game, &word_set_name)?;
+            let lang = match app.lang {
+                Lang::En => String::new(),
+                lang => format!(" --lang {}", lang.name()),
+            };
+            let mode = match (game_type, game.date()) {
+                // by date, so it still counts towards the stats
+                (GameType::Daily(_), Some(date)) => {
+                    format!("date {}-{}-{}", date.year(), date.month() as u8, date.day())
+                }
+                (GameType::Daily(day), None) => format!("day {}", day),
+                (GameType::Random(seed), _) => format!("random --seed {}", code::encode(seed)),
+                (GameType::Custom(code), _) => format!("code {}", code::encode(code)),
+            };
+            println!("Game saved. Run `wordle{} {}` to carry on", lang, mode);
+        }
+        Exit::Quit(_) => {}
     }
 
     Ok(())
@@ -0,0 +1,145 @@
+//! Short codes for sharing games, using [Crockford's base32](https://www.crockford.com/base32.html)
+
+use crate::words::{Fnv1a, WordSet};
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Puzzle codes are 25 bits, so they encode to at most 5 letters
+const PUZZLE_BITS: u32 = 25;
+const PUZZLE_MASK: u64 = (1 << PUZZLE_BITS) - 1;
+/// Odd, so that multiplying by it can be reversed
+const PUZZLE_MUL: u64 = 0x5bd1e99;
+
+/// Encodes a number as a short code
+///
+/// ```
+/// use cl_wordle::code;
+/// assert_eq!(code::encode(0), "0");
+/// assert_eq!(code::encode(1234567), "15NM7");
+/// assert_eq!(code::decode("15nm7"), Some(1234567));
+/// ```
+pub fn encode(mut n: u64) -> String {
+    let mut code = Vec::new();
+    loop {
+        code.push(ALPHABET[(n % 32) as usize]);
+        n /= 32;
+        if n == 0 {
+            break;
+        }
+    }
+    code.reverse();
+    String::from_utf8(code).unwrap()
+}
+
+/// Decodes a code created by [`encode`].
+///
+/// Decoding is case insensitive, ignores any `-`, and treats the
+/// easily confused letters `O`, `I` and `L` as `0`, `1` and `1`.
+/// Returns `None` if the code is invalid.
+pub fn decode(code: &str) -> Option<u64> {
+    let mut n = 0u64;
+    let mut empty = true;
+    for c in code.bytes().filter(|&c| c != b'-') {
+        let c = match c.to_ascii_uppercase() {
+            b'O' => b'0',
+            b'I' | b'L' => b'1',
+            c => c,
+        };
+        let digit = ALPHABET.iter().position(|&a| a == c)? as u64;
+        n = n.checked_mul(32)?.checked_add(digit)?;
+        empty = false;
+    }
+    if empty {
+        None
+    } else {
+        Some(n)
+    }
+}
+
+/// Creates a puzzle code for the given solution, which doesn't reveal the word.
+/// The code can only be turned back into the solution using the same word set.
+/// Returns `None` if the word isn't a solution in the word set.
+///
+/// ```
+/// use cl_wordle::{code, words::{NYTIMES, ORIGINAL}};
+/// let puzzle = code::puzzle_code(NYTIMES, "crane").unwrap();
+/// assert_eq!(code::puzzle_solution(NYTIMES, puzzle), Some("crane"));
+/// assert_ne!(code::puzzle_solution(ORIGINAL, puzzle), Some("crane"));
+/// ```
+pub fn puzzle_code(word_set: WordSet<'_>, solution: &str) -> Option<u64> {
+    let index = word_set.solutions.iter().position(|&w| w == solution)? as u64;
+
+    let mut x = index.wrapping_add(fingerprint(word_set)) & PUZZLE_MASK;
+    x = x.wrapping_mul(PUZZLE_MUL) & PUZZLE_MASK;
+    x ^= x >> 13;
+    x = x.wrapping_mul(PUZZLE_MUL) & PUZZLE_MASK;
+    Some(x)
+}
+
+/// Gets the solution from a code created by [`puzzle_code`].
+/// Returns `None` if the code isn't valid for this word set.
+pub fn puzzle_solution<'a>(word_set: WordSet<'a>, code: u64) -> Option<&'a str> {
+    if code > PUZZLE_MASK {
+        return None;
+    }
+
+    let inverse = mul_inverse(PUZZLE_MUL);
+    let mut x = code.wrapping_mul(inverse) & PUZZLE_MASK;
+    // undoes itself since the shift is more than half the bits
+    x ^= x >> 13;
+    x = x.wrapping_mul(inverse) & PUZZLE_MASK;
+    let index = x.wrapping_sub(fingerprint(word_set)) & PUZZLE_MASK;
+
+    word_set.solutions.get(index as usize).copied()
+}
+
+/// A hash of the solutions, so codes are tied to the word set
+fn fingerprint(word_set: WordSet<'_>) -> u64 {
+    let mut hash = Fnv1a::default();
+    hash.write_list(word_set.solutions);
+    hash.finish()
+}
+
+/// Finds `y` such that `x * y = 1 (mod 2^64)`, using newton's method
+fn mul_inverse(x: u64) -> u64 {
+    let mut y = x;
+    for _ in 0..5 {
+        y = y.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(y)));
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, puzzle_code, puzzle_solution};
+    use crate::words::NYTIMES;
+    use test_case::test_case;
+
+    #[test_case(0)]
+    #[test_case(31)]
+    #[test_case(32)]
+    #[test_case(u64::MAX)]
+    fn round_trip(n: u64) {
+        assert_eq!(decode(&encode(n)), Some(n));
+    }
+
+    #[test_case("" ; "empty")]
+    #[test_case("U" ; "invalid letter")]
+    #[test_case("ZZZZZZZZZZZZZZ" ; "overflow")]
+    fn invalid(code: &str) {
+        assert_eq!(decode(code), None);
+    }
+
+    #[test]
+    fn confusable_letters() {
+        assert_eq!(decode("1O-Li"), decode("1011"));
+    }
+
+    #[test]
+    fn puzzle_round_trip() {
+        for &word in NYTIMES.solutions {
+            let code = puzzle_code(NYTIMES, word).unwrap();
+            assert_eq!(puzzle_solution(NYTIMES, code), Some(word));
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Difficulty ratings for the solutions of a word set
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use crate::words::WordSet;
+
+/// How hard a solution is to find, relative to the other solutions in its word set
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Medium => write!(f, "medium"),
+            Difficulty::Hard => write!(f, "hard"),
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(eyre::eyre!("expected one of easy, medium or hard")),
+        }
+    }
+}
+
+/// Difficulty scores for every solution in a word set.
+///
+/// A solution's score is the number of other solutions that differ from it by only
+/// one letter in the same position, such as `light`, `might` and `night`. These
+/// are hard because a guess can reveal 4 exact letters and still leave many options.
+/// Ties are broken by how rare the solution's letters are in each position.
+///
+/// ```
+/// use cl_wordle::{difficulty::{Difficulty, Ratings}, words::NYTIMES};
+/// let ratings = Ratings::new(NYTIMES);
+/// assert!(ratings.score("light").unwrap() > ratings.score("crane").unwrap());
+/// assert_eq!(ratings.difficulty("light"), Some(Difficulty::Hard));
+/// assert_eq!(ratings.difficulty("zzzzz"), None);
+/// ```
+pub struct Ratings<'a> {
+    solutions: &'a [&'a str],
+    scores: HashMap<&'a str, f64>,
+    bands: HashMap<&'a str, Difficulty>,
+}
+
+impl<'a> Ratings<'a> {
+    /// Rate all of the solutions in the given word set
+    pub fn new(word_set: WordSet<'a>) -> Self {
+        let solutions = word_set.solutions;
+        let letters: Vec<Vec<char>> = solutions.iter().map(|w| w.chars().collect()).collect();
+
+        let mut counts: [HashMap<char, usize>; 5] = Default::default();
+        for word in &letters {
+            for (count, &c) in counts.iter_mut().zip(word) {
+                *count.entry(c).or_default() += 1;
+            }
+        }
+
+        let scores: Vec<f64> = letters
+            .iter()
+            .map(|word| {
+                let neighbours = letters
+                    .iter()
+                    .filter(|other| word.iter().zip(*other).filter(|(a, b)| a != b).count() == 1)
+                    .count();
+                let commonness: f64 = counts
+                    .iter()
+                    .zip(word)
+                    .map(|(count, c)| count[c] as f64 / solutions.len() as f64)
+                    .sum::<f64>()
+                    / 5.0;
+                neighbours as f64 + (1.0 - commonness)
+            })
+            .collect();
+
+        // split the solutions into thirds by rank rather than by score, so tied scores
+        // can't push a band empty. The sort is stable, so ties keep their list order
+        let mut ranked: Vec<usize> = (0..solutions.len()).collect();
+        ranked.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+        let bands = ranked
+            .iter()
+            .enumerate()
+            .map(|(rank, &i)| {
+                let band = match rank * 3 / solutions.len() {
+                    0 => Difficulty::Easy,
+                    1 => Difficulty::Medium,
+                    _ => Difficulty::Hard,
+                };
+                (solutions[i], band)
+            })
+            .collect();
+
+        Self {
+            solutions,
+            scores: solutions.iter().copied().zip(scores).collect(),
+            bands,
+        }
+    }
+
+    /// Gets the difficulty score for the given solution. Higher is harder
+    pub fn score(&self, solution: &str) -> Option<f64> {
+        self.scores.get(solution).copied()
+    }
+
+    /// Gets the difficulty band for the given solution
+    pub fn difficulty(&self, solution: &str) -> Option<Difficulty> {
+        self.bands.get(solution).copied()
+    }
+
+    /// Gets the solutions in a difficulty band, in the order of the solutions list.
+    /// Every band has a solution as long as there are at least 3 solutions
+    ///
+    /// ```
+    /// use cl_wordle::{difficulty::{Difficulty, Ratings}, words::WordSetBuf};
+    /// let word_set = WordSetBuf::parse("cigar\nrebut\n", "").unwrap().leak();
+    /// let ratings = Ratings::new(word_set);
+    /// assert_eq!(ratings.solutions(Difficulty::Easy).count(), 1);
+    /// assert_eq!(ratings.solutions(Difficulty::Hard).count(), 0);
+    /// ```
+    pub fn solutions(&self, difficulty: Difficulty) -> impl Iterator<Item = &'a str> + '_ {
+        self.solutions
+            .iter()
+            .copied()
+            .filter(move |solution| self.difficulty(solution) == Some(difficulty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Difficulty, Ratings};
+    use crate::words::{WordSetBuf, NYTIMES};
+
+    #[test]
+    fn bands_are_balanced() {
+        let ratings = Ratings::new(NYTIMES);
+        for band in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            let n = NYTIMES
+                .solutions
+                .iter()
+                .filter(|w| ratings.difficulty(w) == Some(band))
+                .count();
+            let third = NYTIMES.solutions.len() / 3;
+            assert!(
+                n > third / 2 && n < third * 2,
+                "{} has {} solutions",
+                band,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn tied_scores_fill_every_band() {
+        // every word differs from every other by one letter, so they all have the same score
+        let word_set = WordSetBuf::parse("aaaaa\nbaaaa\ncaaaa\n", "")
+            .unwrap()
+            .leak();
+        let ratings = Ratings::new(word_set);
+        for band in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            assert_eq!(ratings.solutions(band).count(), 1, "{}", band);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Reading and writing the line based files that games are kept in between runs
+
+use std::{fs, io, path::Path, str::FromStr};
+
+use eyre::{Result, WrapErr};
+
+/// Reads a whole file. Returns `None` if it doesn't exist yet
+pub(crate) fn read(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(Some(s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).wrap_err_with(|| format!("could not read {}", path.display())),
+    }
+}
+
+/// Reads and parses a file, or gives the default if it doesn't exist yet
+pub(crate) fn load<T: Default>(path: &Path, parse: impl FnOnce(&str) -> Result<T>) -> Result<T> {
+    match read(path)? {
+        Some(s) => parse(&s).wrap_err_with(|| format!("could not parse {}", path.display())),
+        None => Ok(T::default()),
+    }
+}
+
+/// Writes a file, creating its directory if needed
+pub(crate) fn save(path: &Path, contents: &str) -> Result<()> {
+    create_parent(path)?;
+    fs::write(path, contents).wrap_err_with(|| format!("could not write {}", path.display()))
+}
+
+/// Adds to the end of a file, creating it and its directory if needed
+#[cfg(feature = "time")]
+pub(crate) fn append(path: &Path, contents: &str) -> Result<()> {
+    use std::io::Write;
+
+    create_parent(path)?;
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .wrap_err_with(|| format!("could not write {}", path.display()))
+}
+
+/// Creates the directory a file goes in, if it doesn't exist yet
+fn create_parent(path: &Path) -> Result<()> {
+    match path.parent() {
+        Some(dir) => {
+            fs::create_dir_all(dir).wrap_err_with(|| format!("could not create {}", dir.display()))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Parses every line that isn't empty, naming the line of the first error
+pub(crate) fn parse_lines<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr<Err = eyre::Report>,
+{
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().wrap_err_with(|| format!("line {}", i + 1)))
+        .collect()
+}
+
+/// Writes whether hard mode was on, as parsed by [`parse_mode`]
+pub(crate) fn mode(hard: bool) -> &'static str {
+    if hard {
+        "hard"
+    } else {
+        "normal"
+    }
+}
+
+/// Parses whether hard mode was on
+pub(crate) fn parse_mode(s: &str) -> Result<bool> {
+    match s {
+        "normal" => Ok(false),
+        "hard" => Ok(true),
+        _ => Err(eyre::eyre!("{:?} is not normal or hard", s)),
+    }
+}
+
+/// Writes whether the player had help, as parsed by [`parse_assisted`]
+pub(crate) fn assisted(assisted: bool) -> &'static str {
+    if assisted {
+        "assisted"
+    } else {
+        "unassisted"
+    }
+}
+
+/// Parses whether the player had help
+pub(crate) fn parse_assisted(s: &str) -> Result<bool> {
+    match s {
+        "unassisted" => Ok(false),
+        "assisted" => Ok(true),
+        _ => Err(eyre::eyre!("{:?} is not unassisted or assisted", s)),
+    }
+}
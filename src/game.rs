@@ -4,13 +4,15 @@ use crate::{
     state::{GuessError, State},
     Matches, words::WordSet,
 };
-use eyre::{ensure, Result};
+use eyre::{eyre, Result};
 
 /// A Wrapper over [`State`] that manages
 /// creation and playing of games
 pub struct Game {
     state: State,
     hard_mode: bool,
+    min_commonness: Option<f64>,
+    assisted: bool,
     game_type: GameType,
 }
 
@@ -29,24 +31,51 @@ impl Game {
         use eyre::WrapErr;
         let now =
             time::OffsetDateTime::now_local().wrap_err("could not determine local timezone")?;
-        Ok(Self::from_date(now.date(), word_set))
+        Self::from_date(now.date(), word_set)
     }
 
-    /// Create a new game based on the given word
+    /// Create a new game based on the given word.
+    ///
+    /// The game gets a puzzle code, which can be shared
+    /// so others can play the same word without it being revealed.
+    ///
+    /// ```
+    /// use cl_wordle::{game::{Game, GameType}, words::NYTIMES};
+    /// let game = Game::custom("crane".to_owned(), NYTIMES).unwrap();
+    /// let code = match game.game_type() {
+    ///     GameType::Custom(code) => code,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(Game::from_code(code, NYTIMES).unwrap().solution(), "crane");
+    /// ```
     pub fn custom(solution: String, word_set: WordSet<'static>) -> Result<Self> {
-        ensure!(
-            word_set.solutions.contains(&&*solution),
-            "{} is not a valid solution",
-            solution
-        );
-        Ok(Self::new_raw(solution, GameType::Custom, word_set))
+        let code = crate::code::puzzle_code(word_set, &solution)
+            .ok_or_else(|| eyre!("{} is not a valid solution", solution))?;
+        Ok(Self::new_raw(solution, GameType::Custom(code), word_set))
+    }
+
+    /// Create a new game from the puzzle code of a custom game
+    ///
+    /// # Errors
+    /// If the code was not created using the same word set
+    pub fn from_code(code: u64, word_set: WordSet<'static>) -> Result<Self> {
+        let solution = crate::code::puzzle_solution(word_set, code)
+            .ok_or_else(|| eyre!("not a valid puzzle code for this word set"))?;
+        Ok(Self::new_raw(
+            solution.to_owned(),
+            GameType::Custom(code),
+            word_set,
+        ))
     }
 
     /// Create a new game based on the given date
+    ///
+    /// # Errors
+    /// If the date is before the first day of the word set
     #[cfg(feature = "time")]
-    pub fn from_date(date: time::Date, word_set: WordSet<'static>) -> Self {
-        let day = word_set.get_day(date);
-        Self::from_day(day, word_set)
+    pub fn from_date(date: time::Date, word_set: WordSet<'static>) -> Result<Self> {
+        let day = word_set.get_day(date)?;
+        Ok(Self::from_day(day, word_set))
     }
 
     /// Create a new game based on the given day number
@@ -55,10 +84,27 @@ impl Game {
         Self::new_raw(solution, GameType::Daily(day), word_set)
     }
 
+    /// Create a new game based on the given seed.
+    /// The same seed will always pick the same solution from a word set
+    ///
+    /// ```
+    /// use cl_wordle::{game::Game, words::NYTIMES};
+    /// let game = Game::from_seed(1234567, NYTIMES);
+    /// assert_eq!(game.game_type().to_string(), "#15NM7");
+    /// assert_eq!(game.solution(), Game::from_seed(1234567, NYTIMES).solution());
+    /// ```
+    pub fn from_seed(seed: u64, word_set: WordSet<'static>) -> Self {
+        let i = splitmix64(seed) % word_set.solutions.len() as u64;
+        let solution = word_set.solutions[i as usize].to_owned();
+        Self::new_raw(solution, GameType::Random(seed), word_set)
+    }
+
     fn new_raw(solution: String, game_type: GameType, word_set: WordSet<'static>) -> Self {
         Self {
             state: State::new(solution, word_set),
             hard_mode: false,
+            min_commonness: None,
+            assisted: false,
             game_type,
         }
     }
@@ -70,6 +116,39 @@ impl Game {
         self.hard_mode = true;
     }
 
+    /// Only accept guesses that are at least this common,
+    /// see [`WordSet::commonness`]. Useful for new players
+    ///
+    /// ```
+    /// use cl_wordle::{game::Game, words::NYTIMES};
+    /// let mut game = Game::from_day(0, NYTIMES);
+    /// game.common_words_only(0.5);
+    /// assert!(game.check("crane").is_ok());
+    /// assert!(game.check("zupas").is_err());
+    /// ```
+    pub fn common_words_only(&mut self, min_commonness: f64) {
+        self.min_commonness = Some(min_commonness);
+    }
+
+    /// Marks that the player had help, such as hints from a solver.
+    /// This is shown on the share card
+    ///
+    /// ```
+    /// use cl_wordle::game::Game;
+    /// let mut game = Game::from_day(0, cl_wordle::words::ORIGINAL);
+    /// game.guess("cigar").unwrap();
+    /// game.mark_assisted();
+    /// assert_eq!(game.share().to_string(), "Wordle 0 1/6 (assisted)\n🟩🟩🟩🟩🟩");
+    /// ```
+    pub fn mark_assisted(&mut self) {
+        self.assisted = true;
+    }
+
+    /// Determines if the player had help in this game, see [`Game::mark_assisted`]
+    pub fn is_assisted(&self) -> bool {
+        self.assisted
+    }
+
     /// Get the number of maximum possible guesses
     pub fn max_guess(&self) -> usize {
         self.state.max_guesses()
@@ -80,12 +159,17 @@ impl Game {
         self.max_guesses().min(self.state.guesses().count() + 1)
     }
 
+    /// Determines if this game is being played in hard mode
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
     /// Indicate whether hard mode is active or not
     pub fn hard_mode_indicator(&self) -> &str {
         if self.hard_mode {
-            return "*"
+            "*"
         } else {
-            return ""
+            ""
         }
     }
 
@@ -94,12 +178,66 @@ impl Game {
         self.game_type
     }
 
+    /// Get the date of this game, if it is a daily game
+    #[cfg(feature = "time")]
+    pub fn date(&self) -> Option<time::Date> {
+        match self.game_type {
+            GameType::Daily(day) => self.word_set().date_for_day(day),
+            GameType::Random(_) | GameType::Custom(_) => None,
+        }
+    }
+
+    /// Determines if this is today's daily game, in local time
+    ///
+    /// ```
+    /// use cl_wordle::{game::Game, words::NYTIMES};
+    /// assert!(!Game::from_day(0, NYTIMES).is_today());
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn is_today(&self) -> bool {
+        match (self.date(), time::OffsetDateTime::now_local()) {
+            (Some(date), Ok(now)) => date == now.date(),
+            _ => false,
+        }
+    }
+
+    /// How long until the next daily game starts, at midnight local time.
+    /// `None` if this isn't today's daily game
+    ///
+    /// ```
+    /// use cl_wordle::{game::Game, words::NYTIMES};
+    /// assert!(Game::from_day(0, NYTIMES).next_daily_in().is_none());
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn next_daily_in(&self) -> Option<time::Duration> {
+        if !self.is_today() {
+            return None;
+        }
+        let now = time::OffsetDateTime::now_local().ok()?;
+        let midnight = now.date().next_day()?.midnight().assume_offset(now.offset());
+        Some(midnight - now)
+    }
+
+    /// Check if a guess would be accepted, without making it.
+    ///
+    /// # Errors
+    /// If the guess is an invalid word, or if it doesn't match the
+    /// requirements of hard mode or common words only, this function will return an error
+    pub fn check(&self, word: &str) -> Result<(), GuessError> {
+        self.state.check(word, self.hard_mode)?;
+        match self.min_commonness {
+            Some(min) if self.word_set().commonness(word) < min => Err(GuessError::Uncommon),
+            _ => Ok(()),
+        }
+    }
+
     /// Make a guess.
     ///
     /// # Errors
     /// If the guess is an invalid word, or if it doesn't match the
-    /// requirements of hard mode, this function will return an error
+    /// requirements of hard mode or common words only, this function will return an error
     pub fn guess(&mut self, word: &str) -> Result<Matches, GuessError> {
+        self.check(word)?;
         self.state.guess(word, self.hard_mode)
     }
 
@@ -126,17 +264,45 @@ impl Game {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GameType {
     Daily(usize),
-    Custom,
+    /// A random game, created from the given seed.
+    /// Displayed as a short code that can be used to replay the game
+    Random(u64),
+    /// A game with a chosen word, with the given puzzle code.
+    /// See [`Game::custom`]
+    Custom(u64),
+}
+
+impl GameType {
+    /// The kind of game, and its day, seed or puzzle code, as written in the data files
+    pub(crate) fn to_fields(self) -> (&'static str, u64) {
+        match self {
+            GameType::Daily(day) => ("daily", day as u64),
+            GameType::Random(seed) => ("random", seed),
+            GameType::Custom(code) => ("custom", code),
+        }
+    }
+
+    /// Parses the fields written by [`GameType::to_fields`]
+    pub(crate) fn from_fields(kind: &str, n: &str) -> Result<Self> {
+        let n: u64 = n.parse()?;
+        match kind {
+            "daily" => Ok(GameType::Daily(usize::try_from(n)?)),
+            "random" => Ok(GameType::Random(n)),
+            "custom" => Ok(GameType::Custom(n)),
+            _ => Err(eyre!("{:?} is not daily, random or custom", kind)),
+        }
+    }
 }
 
 impl fmt::Display for GameType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             GameType::Daily(day) => write!(f, "{}", day),
-            GameType::Custom => write!(f, "custom"),
+            GameType::Random(seed) => write!(f, "#{}", crate::code::encode(*seed)),
+            GameType::Custom(code) => write!(f, "custom #{}", crate::code::encode(*code)),
         }
     }
 }
@@ -164,7 +330,22 @@ pub struct GameShare(Game);
 impl fmt::Display for GameShare {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Wordle {game_type} ", game_type = self.0.game_type())?;
-        self.0.display_score_card(f, self.0.hard_mode)?;
+        self.0.display_score(&mut *f, self.0.hard_mode)?;
+        if self.0.assisted {
+            write!(f, " (assisted)")?;
+        }
+        for (_, matches) in self.0.guesses() {
+            write!(f, "\n{}", matches)?;
+        }
         Ok(())
     }
 }
+
+/// A small, fast hash. Used so that nearby seeds pick unrelated solutions
+/// <https://prng.di.unimi.it/splitmix64.c>
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,259 @@
+//! A record of every finished game
+
+use std::{
+    fmt::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use eyre::{eyre, Result};
+use time::{macros::format_description, Date};
+
+use crate::{
+    file,
+    game::{Game, GameType},
+    protocol::write_str,
+};
+
+/// A finished game
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// The day the game was finished
+    pub played: Date,
+    /// The name of the word set it was played with, chosen by the caller
+    pub word_set: String,
+    pub game_type: GameType,
+    pub hard_mode: bool,
+    /// Whether the player had help, see [`Game::mark_assisted`]
+    pub assisted: bool,
+    pub solution: String,
+    pub guesses: Vec<String>,
+}
+
+impl Record {
+    /// Records a game, finished on the given day
+    pub fn new(game: &Game, word_set: &str, played: Date) -> Self {
+        Self {
+            played,
+            word_set: word_set.to_owned(),
+            game_type: game.game_type(),
+            hard_mode: game.is_hard_mode(),
+            assisted: game.is_assisted(),
+            solution: game.solution().to_owned(),
+            guesses: game.guesses().map(|(guess, _)| guess.to_owned()).collect(),
+        }
+    }
+
+    /// Determines if the solution was found
+    pub fn won(&self) -> bool {
+        self.guesses.last() == Some(&self.solution)
+    }
+
+    /// The kind of game, as written in the history
+    pub fn kind(&self) -> &'static str {
+        self.game_type.to_fields().0
+    }
+
+    fn score(&self) -> String {
+        let guesses = if self.won() {
+            self.guesses.len().to_string()
+        } else {
+            "X".to_owned()
+        };
+        let hard_mode = if self.hard_mode { "*" } else { "" };
+        format!("{}/6{}", guesses, hard_mode)
+    }
+}
+
+impl fmt::Display for Record {
+    /// Writes the record as a single line of
+    /// `<played> <word set> <daily|random|custom> <day|seed|code> <normal|hard> <unassisted|assisted> <solution> <guesses>...`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, n) = self.game_type.to_fields();
+        write!(
+            f,
+            "{} {} {} {} {} {} {}",
+            self.played,
+            self.word_set,
+            kind,
+            n,
+            file::mode(self.hard_mode),
+            file::assisted(self.assisted),
+            self.solution,
+        )?;
+        for guess in &self.guesses {
+            write!(f, " {}", guess)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Record {
+    type Err = eyre::Report;
+
+    /// Parses a line written by the [`Display`](fmt::Display) impl
+    ///
+    /// ```
+    /// use cl_wordle::{game::GameType, history::Record};
+    /// let record: Record = "2022-02-19 en daily 245 hard unassisted sissy crane sissy".parse().unwrap();
+    /// assert_eq!(record.game_type, GameType::Daily(245));
+    /// assert!(record.won());
+    /// assert_eq!(record.to_string(), "2022-02-19 en daily 245 hard unassisted sissy crane sissy");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let mut next = || fields.next().ok_or_else(|| eyre!("expected more fields"));
+
+        let played = Date::parse(next()?, format_description!("[year]-[month]-[day]"))?;
+        let word_set = next()?.to_owned();
+        let game_type = GameType::from_fields(next()?, next()?)?;
+        let hard_mode = file::parse_mode(next()?)?;
+        let assisted = file::parse_assisted(next()?)?;
+        let solution = next()?.to_owned();
+
+        Ok(Self {
+            played,
+            word_set,
+            game_type,
+            hard_mode,
+            assisted,
+            solution,
+            guesses: fields.map(str::to_owned).collect(),
+        })
+    }
+}
+
+/// Every finished game, oldest first
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    pub records: Vec<Record>,
+}
+
+impl History {
+    /// Parses records, one per line
+    ///
+    /// # Errors
+    /// If any line is not a valid [`Record`]
+    pub fn parse(s: &str) -> Result<Self> {
+        Ok(Self {
+            records: file::parse_lines(s)?,
+        })
+    }
+
+    /// Loads the history from a file written by [`History::append`].
+    /// If the file doesn't exist yet, there is no history
+    ///
+    /// # Errors
+    /// If the file can't be read or parsed
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        file::load(path.as_ref(), Self::parse)
+    }
+
+    /// Adds a record to the end of a history file, creating it if needed
+    ///
+    /// # Errors
+    /// If the file can't be written
+    pub fn append(path: impl AsRef<Path>, record: &Record) -> Result<()> {
+        file::append(path.as_ref(), &format!("{}\n", record))
+    }
+
+    /// Writes the records as CSV, with a header row. The guesses are separated by spaces
+    ///
+    /// ```
+    /// use cl_wordle::history::History;
+    /// let history = History::parse("2022-02-19 en daily 245 hard unassisted sissy crane sissy").unwrap();
+    /// assert_eq!(history.to_csv(), "\
+    /// played,word_set,kind,game,hard_mode,assisted,solution,won,score,guesses
+    /// 2022-02-19,en,daily,245,true,false,sissy,true,2/6*,crane sissy
+    /// ");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "played,word_set,kind,game,hard_mode,assisted,solution,won,score,guesses\n",
+        );
+        for record in &self.records {
+            let game = match record.game_type {
+                GameType::Daily(day) => day.to_string(),
+                GameType::Random(code) | GameType::Custom(code) => crate::code::encode(code),
+            };
+            let fields = [
+                record.played.to_string(),
+                csv_field(&record.word_set),
+                record.kind().to_owned(),
+                game,
+                record.hard_mode.to_string(),
+                record.assisted.to_string(),
+                csv_field(&record.solution),
+                record.won().to_string(),
+                record.score(),
+                csv_field(&record.guesses.join(" ")),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes the records as a JSON array of objects
+    ///
+    /// ```
+    /// use cl_wordle::history::History;
+    /// let history = History::parse("2022-02-19 en random 1234567 normal assisted sissy crane sissy").unwrap();
+    /// assert_eq!(
+    ///     history.to_json(),
+    ///     r#"[{"played":"2022-02-19","word_set":"en","kind":"random","game":"15NM7","hard_mode":false,"assisted":true,"solution":"sissy","won":true,"score":"2/6","guesses":["crane","sissy"]}]"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json).unwrap();
+        json
+    }
+
+    fn write_json(&self, w: &mut String) -> fmt::Result {
+        write!(w, "[")?;
+        for (i, record) in self.records.iter().enumerate() {
+            if i > 0 {
+                write!(w, ",")?;
+            }
+            write!(w, r#"{{"played":"{}","word_set":"#, record.played)?;
+            write_str(w, &record.word_set)?;
+            write!(w, r#","kind":"{}","game":"#, record.kind())?;
+            match record.game_type {
+                GameType::Daily(day) => write!(w, "{}", day)?,
+                GameType::Random(code) | GameType::Custom(code) => {
+                    write!(w, r#""{}""#, crate::code::encode(code))?
+                }
+            }
+            write!(
+                w,
+                r#","hard_mode":{},"assisted":{},"solution":"#,
+                record.hard_mode, record.assisted
+            )?;
+            write_str(w, &record.solution)?;
+            write!(
+                w,
+                r#","won":{},"score":"{}","guesses":["#,
+                record.won(),
+                record.score()
+            )?;
+            for (i, guess) in record.guesses.iter().enumerate() {
+                if i > 0 {
+                    write!(w, ",")?;
+                }
+                write_str(w, guess)?;
+            }
+            write!(w, "]}}")?;
+        }
+        write!(w, "]")
+    }
+}
+
+/// Quotes a CSV field if it needs to be
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
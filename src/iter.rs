@@ -14,7 +14,7 @@ impl<'a> Iterator for StateIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         self.guesses
             .next()
-            .map(|input| (input.as_str(), crate::diff(&*input, self.solution)))
+            .map(|input| (input.as_str(), crate::diff(input, self.solution)))
     }
 }
 
@@ -22,6 +22,6 @@ impl<'a> DoubleEndedIterator for StateIter<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.guesses
             .next_back()
-            .map(|input| (input.as_str(), crate::diff(&*input, self.solution)))
+            .map(|input| (input.as_str(), crate::diff(input, self.solution)))
     }
 }
@@ -1,9 +1,19 @@
-use std::{fmt::Display, ops::Deref};
+use std::{fmt::Display, ops::Deref, str::FromStr};
 
 pub mod words;
 pub mod state;
 pub mod game;
 pub mod iter;
+pub mod code;
+pub mod difficulty;
+pub mod solver;
+pub mod analysis;
+pub mod protocol;
+pub mod save;
+pub mod stats;
+#[cfg(feature = "time")]
+pub mod history;
+mod file;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 /// Represents a match for a given letter against the solution
@@ -24,6 +34,54 @@ impl Matches {
     pub fn win(self) -> bool {
         self.0 == [Match::Exact; 5]
     }
+
+    /// Writes the matches as plain text, using `g` for exact (green),
+    /// `y` for close (yellow) and `.` for wrong
+    ///
+    /// ```
+    /// use cl_wordle::diff;
+    /// assert_eq!(diff("crane", "cigar").to_code(), "gyy..");
+    /// ```
+    pub fn to_code(self) -> String {
+        self.0
+            .iter()
+            .map(|m| match m {
+                Match::Exact => 'g',
+                Match::Close => 'y',
+                Match::Wrong => '.',
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Matches {
+    type Err = eyre::Report;
+
+    /// Parses matches written by [`Matches::to_code`]. `g`, `y` and `.`
+    /// can also be written as `G`, `Y`, `b`, `x`, `-` or `_`
+    ///
+    /// ```
+    /// use cl_wordle::{diff, Matches};
+    /// assert_eq!("gyy..".parse::<Matches>().unwrap(), diff("crane", "cigar"));
+    /// assert!("gyy.".parse::<Matches>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut matches = [Match::Wrong; 5];
+        let mut n = 0;
+        for c in s.chars() {
+            let m = match c {
+                'g' | 'G' => Match::Exact,
+                'y' | 'Y' => Match::Close,
+                '.' | 'b' | 'x' | '-' | '_' => Match::Wrong,
+                _ => eyre::bail!("{:?} is not one of g, y or .", c),
+            };
+            eyre::ensure!(n < 5, "expected 5 colours");
+            matches[n] = m;
+            n += 1;
+        }
+        eyre::ensure!(n == 5, "expected 5 colours");
+        Ok(Matches(matches))
+    }
 }
 
 impl Display for Match {
@@ -54,23 +112,32 @@ impl Deref for Matches {
 }
 
 pub fn diff(input: &str, solution: &str) -> Matches {
-    debug_assert!(
-        input.is_ascii(),
-        "input guess should only be 5 ascii letters"
-    );
-    debug_assert_eq!(input.len(), 5, "input guess should only be 5 ascii letters");
-    debug_assert!(solution.is_ascii());
-    debug_assert_eq!(solution.len(), 5);
+    if input.is_ascii() && solution.is_ascii() {
+        diff_letters(letters(input.bytes()), letters(solution.bytes()))
+    } else {
+        diff_letters(letters(input.chars()), letters(solution.chars()))
+    }
+}
 
-    let input = input.as_bytes();
-    let mut solution = solution.as_bytes().to_owned();
+fn letters<T: Copy + Default>(word: impl Iterator<Item = T>) -> [T; 5] {
+    let mut letters = [T::default(); 5];
+    let mut n = 0;
+    for (l, c) in letters.iter_mut().zip(word) {
+        *l = c;
+        n += 1;
+    }
+    debug_assert_eq!(n, 5, "words should only be 5 letters");
+    letters
+}
 
+/// Diffs the letters of two words. `T::default()` must not be a valid letter
+fn diff_letters<T: Copy + Default + PartialEq>(input: [T; 5], mut solution: [T; 5]) -> Matches {
     let mut diff = [Match::Wrong; 5];
 
     // find exact matches first
     for (i, &b) in input.iter().enumerate() {
         if solution[i] == b {
-            solution[i] = 0; // letters only match once
+            solution[i] = T::default(); // letters only match once
             diff[i] = Match::Exact;
         }
     }
@@ -81,7 +148,7 @@ pub fn diff(input: &str, solution: &str) -> Matches {
             continue;
         }
         if let Some(j) = solution.iter().position(|&x| x == b) {
-            solution[j] = 0; // letters only match once
+            solution[j] = T::default(); // letters only match once
             diff[i] = Match::Close;
         }
     }
@@ -100,6 +167,7 @@ mod tests {
     #[test_case("class", "crest", [Exact, Wrong, Wrong, Exact, Wrong]; "double letter, one exact, one wrong")]
     #[test_case("stars", "crest", [Close, Close, Wrong, Close, Wrong]; "double letter, one close, one wrong")]
     #[test_case("kills", "skill", [Close, Close, Close, Exact, Close]; "double letter, one exact, one close")]
+    #[test_case("señor", "sueño", [Exact, Close, Close, Close, Wrong]; "non-ascii letters")]
     fn test_diff(input: &str, solution: &str, matches: [Match; 5]) {
         assert_eq!(diff(input, solution).0, matches);
     }
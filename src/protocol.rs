@@ -0,0 +1,261 @@
+//! A line based JSON protocol, so that bots written in any language can play games.
+//!
+//! The bot sends one request per line, and every request gets exactly one
+//! response line back. A [`Response::Start`] is sent before the first request.
+//!
+//! Requests are objects with a `guess`:
+//!
+//! ```text
+//! {"guess":"crane"}
+//! ```
+//!
+//! Responses are objects with a `type` of `start`, `result`, `error` or `game_over`.
+//! Matches are written with [`Matches::to_code`]:
+//!
+//! ```text
+//! {"type":"start","game":"0","language":"English","hard_mode":false,"max_guesses":6}
+//! {"type":"result","guess":"crane","matches":"gyy..","guess_number":1}
+//! {"type":"error","error":"not_in_word_list","message":"\"xxxxx\" is not in the word list"}
+//! {"type":"game_over","win":true,"guesses":4,"solution":"cigar","share":"Wordle 0 4/6\n..."}
+//! ```
+//!
+//! Errors are `not_in_word_list`, `missing_exact` (hard mode, with the `position` of the letter),
+//! `uncommon` and `bad_request`. The game carries on after an error,
+//! though a referee may end the game early instead, as `wordle tournament` does.
+//! A `game_over` response follows the `result` of the final guess.
+
+use std::{fmt, str::FromStr};
+
+use crate::{game::Game, state::GuessError, Matches};
+
+/// A line sent by a bot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Request {
+    Guess(String),
+}
+
+impl FromStr for Request {
+    type Err = eyre::Report;
+
+    /// Parses a request line. Only objects with string values are understood,
+    /// and keys other than `guess` are ignored
+    ///
+    /// ```
+    /// use cl_wordle::protocol::Request;
+    /// let request: Request = r#" {"id": "7", "guess": "crane"} "#.parse().unwrap();
+    /// assert_eq!(request, Request::Guess("crane".to_owned()));
+    /// assert!(r#"{"guess": 5}"#.parse::<Request>().is_err());
+    /// assert!("crane".parse::<Request>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars().peekable();
+        let mut guess = None;
+
+        eyre::ensure!(chars.next() == Some('{'), "expected a JSON object");
+        skip_whitespace(&mut chars);
+        if chars.peek() == Some(&'}') {
+            chars.next();
+        } else {
+            loop {
+                let key = parse_string(&mut chars)?;
+                skip_whitespace(&mut chars);
+                eyre::ensure!(chars.next() == Some(':'), "expected a ':' after {:?}", key);
+                skip_whitespace(&mut chars);
+                let value = parse_string(&mut chars)?;
+                if key == "guess" {
+                    guess = Some(value);
+                }
+                skip_whitespace(&mut chars);
+                match chars.next() {
+                    Some(',') => skip_whitespace(&mut chars),
+                    Some('}') => break,
+                    _ => eyre::bail!("expected a ',' or '}}'"),
+                }
+            }
+        }
+        eyre::ensure!(
+            chars.next().is_none(),
+            "expected the line to end after the object"
+        );
+
+        match guess {
+            Some(guess) => Ok(Request::Guess(guess)),
+            None => eyre::bail!("expected a \"guess\""),
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> eyre::Result<String> {
+    eyre::ensure!(chars.next() == Some('"'), "expected a string");
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            None => eyre::bail!("unterminated string"),
+            Some('"') => return Ok(s),
+            Some('\\') => {
+                let c = match chars.next() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| eyre::eyre!("invalid escape \\u{}", hex))?
+                    }
+                    c => eyre::bail!("invalid escape {:?}", c),
+                };
+                s.push(c);
+            }
+            Some(c) => s.push(c),
+        }
+    }
+}
+
+/// A line sent back to a bot. Displays as a single line of JSON
+pub enum Response<'a> {
+    /// Sent when the game starts, with the name to give the game.
+    /// Day numbers and seeds give away the solution, so referees
+    /// can send another name instead of the [`GameType`](crate::game::GameType)
+    Start(&'a Game, &'a str),
+    /// The matches for a guess that was accepted
+    Result {
+        guess: &'a str,
+        matches: Matches,
+        guess_number: usize,
+    },
+    /// A guess that wasn't accepted
+    Error(&'a GuessError, &'a str),
+    /// A request that couldn't be understood
+    BadRequest(&'a eyre::Report),
+    /// Sent after the result of the last guess
+    GameOver(&'a Game),
+}
+
+impl fmt::Display for Response<'_> {
+    /// ```
+    /// use cl_wordle::{game::Game, protocol::Response, state::GuessError, words::ORIGINAL};
+    /// let mut game = Game::from_day(0, ORIGINAL);
+    /// assert_eq!(
+    ///     Response::Start(&game, "1").to_string(),
+    ///     r#"{"type":"start","game":"1","language":"English","hard_mode":false,"max_guesses":6}"#
+    /// );
+    /// assert_eq!(
+    ///     Response::Error(&GuessError::MissingExactValues(2), "cxxxx").to_string(),
+    ///     r#"{"type":"error","error":"missing_exact","position":2,"message":"\"cxxxx\" must use the exact letter found at position 2"}"#
+    /// );
+    ///
+    /// let matches = game.guess("cigar").unwrap();
+    /// assert_eq!(
+    ///     Response::Result { guess: "cigar", matches, guess_number: 1 }.to_string(),
+    ///     r#"{"type":"result","guess":"cigar","matches":"ggggg","guess_number":1}"#
+    /// );
+    /// assert_eq!(
+    ///     Response::GameOver(&game).to_string(),
+    ///     r#"{"type":"game_over","win":true,"guesses":1,"solution":"cigar","share":"Wordle 0 1/6\n🟩🟩🟩🟩🟩"}"#
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Start(game, name) => {
+                write!(f, r#"{{"type":"start","game":"#)?;
+                write_str(f, name)?;
+                write!(f, r#","language":"#)?;
+                write_str(f, game.word_set().language)?;
+                write!(
+                    f,
+                    r#","hard_mode":{},"max_guesses":{}}}"#,
+                    game.is_hard_mode(),
+                    game.max_guesses()
+                )
+            }
+            Response::Result {
+                guess,
+                matches,
+                guess_number,
+            } => {
+                write!(f, r#"{{"type":"result","guess":"#)?;
+                write_str(f, guess)?;
+                write!(
+                    f,
+                    r#","matches":"{}","guess_number":{}}}"#,
+                    matches.to_code(),
+                    guess_number
+                )
+            }
+            Response::Error(error, guess) => {
+                let (code, message) = match error {
+                    GuessError::NotInWordList => (
+                        "not_in_word_list",
+                        format!("{:?} is not in the word list", guess),
+                    ),
+                    GuessError::MissingExactValues(i) => (
+                        "missing_exact",
+                        format!(
+                            "{:?} must use the exact letter found at position {}",
+                            guess, i
+                        ),
+                    ),
+                    GuessError::Uncommon => (
+                        "uncommon",
+                        format!("{:?} is not a common enough word", guess),
+                    ),
+                };
+                write!(f, r#"{{"type":"error","error":"{}""#, code)?;
+                if let GuessError::MissingExactValues(i) = error {
+                    write!(f, r#","position":{}"#, i)?;
+                }
+                write!(f, r#","message":"#)?;
+                write_str(f, &message)?;
+                write!(f, "}}")
+            }
+            Response::BadRequest(report) => {
+                write!(f, r#"{{"type":"error","error":"bad_request","message":"#)?;
+                write_str(f, &report.to_string())?;
+                write!(f, "}}")
+            }
+            Response::GameOver(game) => {
+                let win = game.game_over().map_or(false, |over| over.is_win());
+                write!(
+                    f,
+                    r#"{{"type":"game_over","win":{},"guesses":{},"solution":"#,
+                    win,
+                    game.guesses().count()
+                )?;
+                write_str(f, game.solution())?;
+                write!(f, r#","share":"#)?;
+                let mut share = format!("Wordle {} ", game.game_type());
+                game.display_score_card(&mut share, game.is_hard_mode())?;
+                write_str(f, &share)?;
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Writes a JSON string, with quotes
+pub(crate) fn write_str(f: &mut impl fmt::Write, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
@@ -0,0 +1,242 @@
+//! Saving games, so unfinished games can be resumed later,
+//! and finished daily games aren't played again
+
+use std::{fmt, path::Path, str::FromStr};
+
+use eyre::{eyre, Result};
+
+use crate::{
+    file,
+    game::{Game, GameType},
+    words::WordSet,
+};
+
+/// A game that was left before it was over, or a finished daily game
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedGame {
+    /// The name of the word set it was played with, chosen by the caller
+    pub word_set: String,
+    pub game_type: GameType,
+    pub hard_mode: bool,
+    pub assisted: bool,
+    pub guesses: Vec<String>,
+}
+
+impl SavedGame {
+    /// Saves the progress of a game, played with the named word set
+    pub fn new(game: &Game, word_set: &str) -> Self {
+        Self {
+            word_set: word_set.to_owned(),
+            game_type: game.game_type(),
+            hard_mode: game.is_hard_mode(),
+            assisted: game.is_assisted(),
+            guesses: game.guesses().map(|(guess, _)| guess.to_owned()).collect(),
+        }
+    }
+
+    /// Starts the game again, and makes the saved guesses
+    ///
+    /// ```
+    /// use cl_wordle::{game::Game, save::SavedGame, words::NYTIMES};
+    /// let mut game = Game::from_seed(1234, NYTIMES);
+    /// game.hard_mode();
+    /// game.guess("crane").unwrap();
+    ///
+    /// let saved = SavedGame::new(&game, "en");
+    /// let restored = saved.restore(NYTIMES).unwrap();
+    /// assert_eq!(restored.solution(), game.solution());
+    /// assert!(restored.is_hard_mode());
+    /// assert_eq!(restored.guesses().collect::<Vec<_>>(), game.guesses().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Errors
+    /// If the game can't be made with this word set, or the guesses aren't allowed
+    pub fn restore(&self, word_set: WordSet<'static>) -> Result<Game> {
+        let mut game = match self.game_type {
+            GameType::Daily(day) => Game::from_day(day, word_set),
+            GameType::Random(seed) => Game::from_seed(seed, word_set),
+            GameType::Custom(code) => Game::from_code(code, word_set)?,
+        };
+        if self.hard_mode {
+            game.hard_mode();
+        }
+        if self.assisted {
+            game.mark_assisted();
+        }
+        for guess in &self.guesses {
+            game.guess(guess)
+                .map_err(|e| eyre!("saved guess {:?} is not allowed: {:?}", guess, e))?;
+        }
+        Ok(game)
+    }
+}
+
+impl fmt::Display for SavedGame {
+    /// Writes the game as a single line of
+    /// `<word set> <daily|random|custom> <day|seed|code> <normal|hard> <unassisted|assisted> <guesses>...`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, n) = self.game_type.to_fields();
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.word_set,
+            kind,
+            n,
+            file::mode(self.hard_mode),
+            file::assisted(self.assisted),
+        )?;
+        for guess in &self.guesses {
+            write!(f, " {}", guess)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SavedGame {
+    type Err = eyre::Report;
+
+    /// Parses a line written by the [`Display`](fmt::Display) impl
+    ///
+    /// ```
+    /// use cl_wordle::{game::GameType, save::SavedGame};
+    /// let saved: SavedGame = "en daily 245 hard unassisted crane slate".parse().unwrap();
+    /// assert_eq!(saved.game_type, GameType::Daily(245));
+    /// assert_eq!(saved.guesses, ["crane", "slate"]);
+    /// assert_eq!(saved.to_string(), "en daily 245 hard unassisted crane slate");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let mut next = || fields.next().ok_or_else(|| eyre!("expected more fields"));
+
+        let word_set = next()?.to_owned();
+        let game_type = GameType::from_fields(next()?, next()?)?;
+        let hard_mode = file::parse_mode(next()?)?;
+        let assisted = file::parse_assisted(next()?)?;
+
+        Ok(Self {
+            word_set,
+            game_type,
+            hard_mode,
+            assisted,
+            guesses: fields.map(str::to_owned).collect(),
+        })
+    }
+}
+
+/// Every saved game, with at most one for each game of a word set.
+/// Displays as one [`SavedGame`] per line
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Saves {
+    games: Vec<SavedGame>,
+}
+
+impl Saves {
+    /// Parses saved games, one per line
+    ///
+    /// # Errors
+    /// If any line is not a valid [`SavedGame`]
+    pub fn parse(s: &str) -> Result<Self> {
+        Ok(Self {
+            games: file::parse_lines(s)?,
+        })
+    }
+
+    /// Parses saved games like [`Saves::parse`], but skips the lines that
+    /// aren't valid, returning an error for each of them
+    ///
+    /// ```
+    /// use cl_wordle::{game::GameType, save::Saves};
+    /// let (saves, errors) = Saves::parse_lossy("en daily 245 hard unassisted crane\nen weekly 3 hard\n");
+    /// assert!(saves.get("en", GameType::Daily(245)).is_some());
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn parse_lossy(s: &str) -> (Self, Vec<eyre::Report>) {
+        let mut saves = Self::default();
+        let mut errors = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match line.parse() {
+                Ok(saved) => saves.games.push(saved),
+                Err(e) => errors.push(e.wrap_err(format!("line {}", i + 1))),
+            }
+        }
+        (saves, errors)
+    }
+
+    /// Loads saved games from a file written by [`Saves::save`].
+    /// If the file doesn't exist yet, there are no saved games
+    ///
+    /// # Errors
+    /// If the file can't be read or parsed
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        file::load(path.as_ref(), Self::parse)
+    }
+
+    /// Loads saved games like [`Saves::load`], but skips the lines that
+    /// can't be parsed, returning an error for each of them
+    ///
+    /// # Errors
+    /// If the file can't be read
+    pub fn load_lossy(path: impl AsRef<Path>) -> Result<(Self, Vec<eyre::Report>)> {
+        let s = file::read(path.as_ref())?.unwrap_or_default();
+        Ok(Self::parse_lossy(&s))
+    }
+
+    /// Writes the saved games to a file, creating its directory if needed
+    ///
+    /// # Errors
+    /// If the file can't be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        file::save(path.as_ref(), &self.to_string())
+    }
+
+    /// Finds the saved game for a game of a word set
+    pub fn get(&self, word_set: &str, game_type: GameType) -> Option<&SavedGame> {
+        self.games
+            .iter()
+            .find(|saved| saved.word_set == word_set && saved.game_type == game_type)
+    }
+
+    /// Saves a game, replacing any earlier save of the same game
+    ///
+    /// ```
+    /// use cl_wordle::{game::GameType, save::{SavedGame, Saves}};
+    /// let mut saves = Saves::default();
+    /// saves.insert("en daily 245 normal unassisted crane".parse().unwrap());
+    /// saves.insert("en daily 245 normal unassisted crane slate".parse().unwrap());
+    /// assert_eq!(saves.get("en", GameType::Daily(245)).unwrap().guesses.len(), 2);
+    /// assert!(saves.get("de", GameType::Daily(245)).is_none());
+    ///
+    /// saves.remove("en", GameType::Daily(245));
+    /// assert_eq!(saves.to_string(), "");
+    /// ```
+    pub fn insert(&mut self, saved: SavedGame) {
+        self.remove(&saved.word_set, saved.game_type);
+        self.games.push(saved);
+    }
+
+    /// Only keeps the saved games that the predicate returns `true` for
+    pub fn retain(&mut self, f: impl FnMut(&SavedGame) -> bool) {
+        self.games.retain(f);
+    }
+
+    /// Removes the saved game for a game of a word set, if there is one
+    pub fn remove(&mut self, word_set: &str, game_type: GameType) -> Option<SavedGame> {
+        let i = self
+            .games
+            .iter()
+            .position(|saved| saved.word_set == word_set && saved.game_type == game_type)?;
+        Some(self.games.remove(i))
+    }
+}
+
+impl fmt::Display for Saves {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for saved in &self.games {
+            writeln!(f, "{}", saved)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! Solvers that play wordle, for use in bots, hints and analysis
+
+use crate::{diff, game::Game, iter::Guess, state::State, words::WordSet, Match, Matches};
+
+mod bench;
+mod entropy;
+mod minimax;
+mod openers;
+mod tree;
+
+pub use bench::Benchmark;
+pub use entropy::Entropy;
+pub use minimax::Minimax;
+pub use openers::{rank_openers, Metric};
+pub use tree::DecisionTree;
+
+/// The number of different [`Matches`] a guess can get
+pub const PATTERNS: usize = 243;
+
+/// A strategy for playing wordle
+pub trait Solver {
+    /// Picks the next word to guess, based on the guesses made so far.
+    /// Solvers must not peek at [`State::solution`]
+    fn next_guess(&self, state: &State) -> String;
+
+    /// Like [`Solver::next_guess`], but only picks guesses that are allowed in hard mode
+    fn next_hard_guess(&self, state: &State) -> String;
+
+    /// Picks the next word to guess in the game, following hard mode if it is enabled
+    fn next_game_guess(&self, game: &Game) -> String {
+        if game.is_hard_mode() {
+            self.next_hard_guess(game)
+        } else {
+            self.next_guess(game)
+        }
+    }
+}
+
+/// Gets the solutions that are still possible after the given guesses
+///
+/// ```
+/// use cl_wordle::{diff, solver, words::NYTIMES};
+/// let remaining = solver::remaining(NYTIMES, [("crane", diff("crane", "cigar"))]);
+/// assert!(remaining.contains(&"cigar"));
+/// assert!(!remaining.contains(&"crane"));
+/// ```
+pub fn remaining<'a, 'b>(
+    word_set: WordSet<'a>,
+    guesses: impl IntoIterator<Item = Guess<'b>>,
+) -> Vec<&'a str> {
+    let guesses: Vec<Guess<'b>> = guesses.into_iter().collect();
+    word_set
+        .solutions
+        .iter()
+        .copied()
+        .filter(|solution| guesses.iter().all(|&(g, m)| diff(g, solution) == m))
+        .collect()
+}
+
+/// Determines if `matches` could ever be given back for `guess`.
+///
+/// Close matches are given out from left to right, so a letter can't be
+/// close if the same letter is wrong earlier in the guess.
+///
+/// ```
+/// use cl_wordle::solver::is_possible;
+/// assert!(is_possible("geese", ".y..g".parse().unwrap()));
+/// // the second `e` can't be close when the first is wrong
+/// assert!(!is_possible("geese", "..y.g".parse().unwrap()));
+/// ```
+pub fn is_possible(guess: &str, matches: Matches) -> bool {
+    let letters: Vec<char> = guess.chars().collect();
+    letters
+        .iter()
+        .zip(matches.iter())
+        .enumerate()
+        .all(|(i, (c, m))| {
+            *m != Match::Close
+                || !letters[..i]
+                    .iter()
+                    .zip(matches.iter())
+                    .any(|(d, n)| d == c && *n == Match::Wrong)
+        })
+}
+
+/// All the words that can be guessed in a word set, solutions first
+pub fn guesses(word_set: WordSet<'_>) -> impl Iterator<Item = &str> {
+    word_set
+        .solutions
+        .iter()
+        .chain(word_set.acceptable)
+        .copied()
+}
+
+/// The words that can be guessed next in the game, optionally following hard mode
+pub fn allowed(state: &State, hard: bool) -> impl Iterator<Item = &'static str> + '_ {
+    guesses(state.word_set()).filter(move |guess| !hard || state.check(guess, true).is_ok())
+}
+
+/// Picks the best guess from `rank`, which scores the allowed guesses
+/// against the remaining solutions, best first. Solvers with an opener
+/// always start with it, since the first guess is the slowest to work out
+/// while every solution is still possible
+fn pick<T>(
+    opener: Option<&str>,
+    state: &State,
+    hard: bool,
+    rank: impl FnOnce(&mut dyn Iterator<Item = &'static str>, &[&str]) -> Vec<(&'static str, T)>,
+) -> String {
+    if let (Some(opener), None) = (opener, state.guesses().next()) {
+        return opener.to_owned();
+    }
+    let candidates = remaining(state.word_set(), state.guesses());
+    rank(&mut allowed(state, hard), &candidates)[0].0.to_owned()
+}
+
+/// Numbers each of the possible [`Matches`] from `0` to [`PATTERNS`],
+/// so they can be used as an index
+pub fn pattern(matches: Matches) -> usize {
+    matches.iter().fold(0, |n, m| {
+        n * 3
+            + match m {
+                Match::Exact => 0,
+                Match::Close => 1,
+                Match::Wrong => 2,
+            }
+    })
+}
+
+/// Counts how many of the candidates would give each pattern, if `guess` was played
+pub fn partition(guess: &str, candidates: &[&str]) -> [usize; PATTERNS] {
+    let mut counts = [0; PATTERNS];
+    for solution in candidates {
+        counts[pattern(diff(guess, solution))] += 1;
+    }
+    counts
+}
+
+/// The average number of solutions that would be left after guessing `guess`,
+/// if the solution is one of `candidates`. Winning leaves none
+pub fn expected_remaining(guess: &str, candidates: &[&str]) -> f64 {
+    let counts = partition(guess, candidates);
+    // pattern 0 is the win, which leaves nothing
+    let squares: usize = counts[1..].iter().map(|n| n * n).sum();
+    squares as f64 / candidates.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pattern, Entropy, Minimax, Solver, PATTERNS};
+    use crate::{diff, game::Game, words::NYTIMES};
+    use test_case::test_case;
+
+    #[test]
+    fn patterns() {
+        assert_eq!(pattern(diff("cigar", "cigar")), 0);
+        // everything is wrong, except a close `r`
+        assert_eq!(pattern(diff("fjord", "cigar")), PATTERNS - 1 - 3);
+    }
+
+    fn solve(solver: impl Solver, mut game: Game) {
+        while game.guesses().count() < game.max_guesses() {
+            let guess = solver.next_game_guess(&game);
+            if game.guess(&guess).unwrap().win() {
+                return;
+            }
+        }
+        panic!("did not find {}", game.solution());
+    }
+
+    #[test_case("cigar")]
+    #[test_case("light")]
+    #[test_case("fuzzy")]
+    fn entropy_solves(solution: &str) {
+        let game = Game::custom(solution.to_owned(), NYTIMES).unwrap();
+        solve(Entropy::with_opener("salet"), game);
+    }
+
+    #[test_case("cigar")]
+    #[test_case("light")]
+    #[test_case("fuzzy")]
+    fn minimax_solves(solution: &str) {
+        let game = Game::custom(solution.to_owned(), NYTIMES).unwrap();
+        solve(Minimax::with_opener("salet"), game);
+    }
+
+    #[test_case("cigar")]
+    #[test_case("light")]
+    #[test_case("fuzzy")]
+    fn hard_mode(solution: &str) {
+        // guessing fails if the solvers break the rules of hard mode
+        let mut game = Game::custom(solution.to_owned(), NYTIMES).unwrap();
+        game.hard_mode();
+        solve(Minimax::with_opener("salet"), game);
+
+        let mut game = Game::custom(solution.to_owned(), NYTIMES).unwrap();
+        game.hard_mode();
+        solve(Entropy::with_opener("salet"), game);
+    }
+}
@@ -0,0 +1,119 @@
+use std::{sync::Arc, thread};
+
+use super::Solver;
+use crate::{state::State, words::WordSet};
+
+/// The results of playing every solution in a word set with a solver.
+///
+/// Unlike [`DecisionTree`](super::DecisionTree), each game is played out on its own,
+/// so solvers that break the rules or run out of guesses are counted as failures
+/// instead of stopping the run.
+///
+/// ```
+/// use std::sync::Arc;
+/// use cl_wordle::{solver::{Benchmark, Minimax}, words::WordSetBuf};
+/// let word_set = WordSetBuf::parse("cigar\nrebut\nsissy\nhumph\nawake", "").unwrap().leak();
+/// let bench = Benchmark::run(Arc::new(Minimax::with_opener("cigar")), word_set, false, 2);
+/// assert_eq!(bench.guess_counts, [0, 1, 4]);
+/// assert_eq!(bench.average_guesses(), 1.8);
+/// assert_eq!(bench.max_guesses(), 2);
+/// assert!(bench.failures.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Benchmark {
+    /// `guess_counts[n]` is the number of solutions found on guess `n`
+    pub guess_counts: Vec<usize>,
+    /// The solutions that weren't found, sorted
+    pub failures: Vec<String>,
+}
+
+impl Benchmark {
+    /// Plays every solution in the word set with the solver, optionally following hard mode.
+    /// The solutions are shared out between the given number of threads
+    pub fn run(
+        solver: Arc<dyn Solver + Send + Sync>,
+        word_set: WordSet<'static>,
+        hard: bool,
+        threads: usize,
+    ) -> Self {
+        let threads = threads.max(1);
+        let handles: Vec<_> = (0..threads)
+            .map(|i| {
+                let solver = Arc::clone(&solver);
+                thread::spawn(move || {
+                    let mut bench = Self::default();
+                    for solution in word_set.solutions.iter().skip(i).step_by(threads) {
+                        match play(&*solver, word_set, hard, solution) {
+                            Some(guesses) => bench.add(guesses, 1),
+                            None => bench.failures.push(solution.to_string()),
+                        }
+                    }
+                    bench
+                })
+            })
+            .collect();
+
+        let mut bench = Self::default();
+        for handle in handles {
+            let part = handle.join().expect("solver panicked");
+            for (guesses, n) in part.guess_counts.into_iter().enumerate() {
+                bench.add(guesses, n);
+            }
+            bench.failures.extend(part.failures);
+        }
+        bench.failures.sort();
+        bench
+    }
+
+    fn add(&mut self, guesses: usize, n: usize) {
+        if self.guess_counts.len() <= guesses {
+            self.guess_counts.resize(guesses + 1, 0);
+        }
+        self.guess_counts[guesses] += n;
+    }
+
+    /// The number of solutions that were found
+    pub fn solved(&self) -> usize {
+        self.guess_counts.iter().sum()
+    }
+
+    /// The average number of guesses needed, for the solutions that were found
+    pub fn average_guesses(&self) -> f64 {
+        let total: usize = self
+            .guess_counts
+            .iter()
+            .enumerate()
+            .map(|(n, c)| n * c)
+            .sum();
+        total as f64 / self.solved() as f64
+    }
+
+    /// The most guesses needed to find any of the solutions that were found
+    pub fn max_guesses(&self) -> usize {
+        self.guess_counts
+            .iter()
+            .rposition(|&n| n > 0)
+            .unwrap_or_default()
+    }
+}
+
+/// Plays a single game, returning how many guesses the solver needed
+fn play(
+    solver: &dyn Solver,
+    word_set: WordSet<'static>,
+    hard: bool,
+    solution: &str,
+) -> Option<usize> {
+    let mut state = State::new(solution.to_owned(), word_set);
+    for n in 1..=state.max_guesses() {
+        let guess = if hard {
+            solver.next_hard_guess(&state)
+        } else {
+            solver.next_guess(&state)
+        };
+        if state.guess(&guess, hard).ok()?.win() {
+            return Some(n);
+        }
+    }
+    None
+}
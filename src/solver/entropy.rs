@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use super::{guesses, partition, pick, Solver};
+use crate::{state::State, words::WordSet};
+
+/// Picks the guess that is expected to give the most information
+/// about the solution, measured in bits.
+///
+/// Every word in the word set is considered, not just the
+/// remaining solutions. Ties go to words that could be the solution.
+///
+/// ```
+/// use cl_wordle::{game::Game, solver::{Entropy, Solver}, words::NYTIMES};
+/// let mut game = Game::from_day(0, NYTIMES);
+/// game.guess("salet").unwrap();
+/// game.guess("corny").unwrap();
+/// assert_eq!(Entropy::default().next_guess(&game), "charm");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Entropy {
+    opener: Option<String>,
+}
+
+impl Entropy {
+    /// Always start with the given word
+    pub fn with_opener(opener: impl Into<String>) -> Self {
+        Self {
+            opener: Some(opener.into()),
+        }
+    }
+
+    /// Scores every guess by how much information it is expected to give,
+    /// if the solution is one of `candidates`. The best guesses come first
+    pub fn rank<'a>(word_set: WordSet<'a>, candidates: &[&str]) -> Vec<(&'a str, f64)> {
+        Self::rank_guesses(guesses(word_set), candidates)
+    }
+
+    /// Like [`Self::rank`], but only scores the given guesses
+    pub fn rank_guesses<'a>(
+        guesses: impl Iterator<Item = &'a str>,
+        candidates: &[&str],
+    ) -> Vec<(&'a str, f64)> {
+        let possible: HashSet<&str> = candidates.iter().copied().collect();
+        let mut ranked: Vec<(&'a str, f64, bool)> = guesses
+            .map(|guess| {
+                let entropy = Self::entropy(guess, candidates);
+                (guess, entropy, possible.contains(guess))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(b.2.cmp(&a.2)));
+        ranked.into_iter().map(|(guess, e, _)| (guess, e)).collect()
+    }
+
+    /// The expected information, in bits, from guessing `guess`
+    /// if the solution is one of `candidates`
+    pub fn entropy(guess: &str, candidates: &[&str]) -> f64 {
+        let total = candidates.len() as f64;
+        partition(guess, candidates)
+            .iter()
+            .filter(|&&n| n > 0)
+            .map(|&n| {
+                let p = n as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl Solver for Entropy {
+    fn next_guess(&self, state: &State) -> String {
+        pick(
+            self.opener.as_deref(),
+            state,
+            false,
+            |guesses, candidates| Self::rank_guesses(guesses, candidates),
+        )
+    }
+
+    fn next_hard_guess(&self, state: &State) -> String {
+        pick(
+            self.opener.as_deref(),
+            state,
+            true,
+            |guesses, candidates| Self::rank_guesses(guesses, candidates),
+        )
+    }
+}
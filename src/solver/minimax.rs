@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use super::{guesses, partition, pick, Solver};
+use crate::{state::State, words::WordSet};
+
+/// Picks the guess that leaves the fewest possible solutions in the worst case.
+///
+/// This gives up a little on the average number of guesses compared to
+/// [`Entropy`](super::Entropy), but is better at guaranteeing a solve.
+/// Ties go to words that could be the solution, then to the guess
+/// that leaves the fewest solutions on average.
+///
+/// ```
+/// use cl_wordle::{game::Game, solver::{Minimax, Solver}, words::NYTIMES};
+/// let mut game = Game::from_day(0, NYTIMES);
+/// game.hard_mode();
+/// game.guess("salet").unwrap();
+/// game.guess("corny").unwrap();
+///
+/// // hard mode means the exact `c` has to be kept
+/// let guess = Minimax::default().next_game_guess(&game);
+/// assert!(guess.starts_with('c'));
+/// assert!(game.check(&guess).is_ok());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Minimax {
+    opener: Option<String>,
+}
+
+impl Minimax {
+    /// Always start with the given word
+    pub fn with_opener(opener: impl Into<String>) -> Self {
+        Self {
+            opener: Some(opener.into()),
+        }
+    }
+
+    /// Scores every guess by the most solutions that could be left after making it,
+    /// if the solution is one of `candidates`. The best guesses come first
+    pub fn rank<'a>(word_set: WordSet<'a>, candidates: &[&str]) -> Vec<(&'a str, usize)> {
+        Self::rank_guesses(guesses(word_set), candidates)
+    }
+
+    /// Like [`Self::rank`], but only scores the given guesses
+    pub fn rank_guesses<'a>(
+        guesses: impl Iterator<Item = &'a str>,
+        candidates: &[&str],
+    ) -> Vec<(&'a str, usize)> {
+        let possible: HashSet<&str> = candidates.iter().copied().collect();
+        let mut ranked: Vec<(&'a str, usize, bool, usize)> = guesses
+            .map(|guess| {
+                let counts = partition(guess, candidates);
+                let worst = counts.iter().copied().max().unwrap_or_default();
+                let squares = counts.iter().map(|n| n * n).sum();
+                (guess, worst, possible.contains(guess), squares)
+            })
+            .collect();
+        ranked.sort_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)).then(a.3.cmp(&b.3)));
+        ranked
+            .into_iter()
+            .map(|(guess, worst, ..)| (guess, worst))
+            .collect()
+    }
+
+    /// The most solutions that could be left after guessing `guess`,
+    /// if the solution is one of `candidates`
+    pub fn worst_case(guess: &str, candidates: &[&str]) -> usize {
+        partition(guess, candidates)
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Solver for Minimax {
+    fn next_guess(&self, state: &State) -> String {
+        pick(
+            self.opener.as_deref(),
+            state,
+            false,
+            |guesses, candidates| Self::rank_guesses(guesses, candidates),
+        )
+    }
+
+    fn next_hard_guess(&self, state: &State) -> String {
+        pick(
+            self.opener.as_deref(),
+            state,
+            true,
+            |guesses, candidates| Self::rank_guesses(guesses, candidates),
+        )
+    }
+}
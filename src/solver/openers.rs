@@ -0,0 +1,69 @@
+use super::{expected_remaining, guesses, Entropy, Minimax};
+use crate::words::WordSet;
+
+/// A way to score a guess, for [`rank_openers`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The information the guess is expected to give, in bits. Higher is better
+    Entropy,
+    /// The average number of solutions left after the guess. Lower is better
+    ExpectedRemaining,
+    /// The most solutions that could be left after the guess. Lower is better
+    WorstCase,
+}
+
+impl Metric {
+    /// Scores `guess`, if the solution is one of `candidates`
+    pub fn score(self, guess: &str, candidates: &[&str]) -> f64 {
+        match self {
+            Metric::Entropy => Entropy::entropy(guess, candidates),
+            Metric::ExpectedRemaining => expected_remaining(guess, candidates),
+            Metric::WorstCase => Minimax::worst_case(guess, candidates) as f64,
+        }
+    }
+
+    /// Determines if higher scores are better
+    pub fn higher_is_better(self) -> bool {
+        match self {
+            Metric::Entropy => true,
+            Metric::ExpectedRemaining | Metric::WorstCase => false,
+        }
+    }
+}
+
+/// Scores every word in the word set as the first guess of a game, best first.
+/// Ties go to words that could be the solution.
+///
+/// This checks every guess against every solution, so it is slow for the full word sets.
+///
+/// ```
+/// use cl_wordle::{solver::{rank_openers, Metric}, words::WordSetBuf};
+/// let word_set = WordSetBuf::parse("cigar\nrebut\nsissy\nhumph", "aahed").unwrap().leak();
+/// let openers = rank_openers(word_set, Metric::WorstCase);
+/// assert_eq!(openers.len(), 5);
+/// assert_eq!(openers[0], ("cigar", 1.0));
+/// // `aahed` tells every solution apart too, but can't win
+/// assert_eq!(openers[2], ("aahed", 1.0));
+/// assert_eq!(openers[4].1, 2.0);
+/// ```
+pub fn rank_openers(word_set: WordSet<'_>, metric: Metric) -> Vec<(&str, f64)> {
+    let candidates = word_set.solutions;
+    let mut ranked: Vec<(&str, f64, bool)> = guesses(word_set)
+        .map(|guess| {
+            let score = metric.score(guess, candidates);
+            (guess, score, word_set.is_solution(guess))
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        let by_score = if metric.higher_is_better() {
+            b.1.partial_cmp(&a.1)
+        } else {
+            a.1.partial_cmp(&b.1)
+        };
+        by_score.unwrap().then(b.2.cmp(&a.2))
+    });
+    ranked
+        .into_iter()
+        .map(|(guess, score, _)| (guess, score))
+        .collect()
+}
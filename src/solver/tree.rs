@@ -0,0 +1,271 @@
+use std::fmt::Write;
+
+use eyre::{bail, eyre, Result};
+
+use super::{partition, pattern, Solver};
+use crate::{diff, state::State, words::WordSet, Matches};
+
+/// A complete guessing strategy for a word set.
+///
+/// Each node is a guess, and each child is the guess to make next
+/// after getting those [`Matches`] back.
+///
+/// ```
+/// use cl_wordle::{solver::{DecisionTree, Minimax}, words::WordSetBuf};
+/// let word_set = WordSetBuf::parse("cigar\nrebut\nsissy\nhumph\nawake", "").unwrap().leak();
+/// let tree = DecisionTree::build(&Minimax::with_opener("cigar"), word_set, false).unwrap();
+/// assert_eq!(tree.guess, "cigar");
+/// assert_eq!(tree.max_guesses(), 2);
+/// assert_eq!(tree.average_guesses(), 1.8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DecisionTree {
+    pub guess: String,
+    /// Whether this guess is one of the possible solutions,
+    /// so the game can be won with it
+    pub solves: bool,
+    /// The next guesses, for each of the matches that
+    /// could be given back. Sorted by the matches
+    pub children: Vec<(Matches, DecisionTree)>,
+}
+
+impl DecisionTree {
+    /// Works out the guesses the solver would make for every solution in the word set,
+    /// optionally following hard mode
+    ///
+    /// # Errors
+    /// If the solver picks a guess that isn't allowed, or
+    /// one that can't tell the remaining solutions apart
+    pub fn build(solver: &dyn Solver, word_set: WordSet<'static>, hard: bool) -> Result<Self> {
+        let mut path = Vec::new();
+        Self::build_node(
+            solver,
+            word_set,
+            hard,
+            &mut path,
+            word_set.solutions.to_vec(),
+        )
+    }
+
+    fn build_node(
+        solver: &dyn Solver,
+        word_set: WordSet<'static>,
+        hard: bool,
+        path: &mut Vec<String>,
+        candidates: Vec<&str>,
+    ) -> Result<Self> {
+        // every candidate gives the same matches for the guesses so far,
+        // so any of them can stand in for the solution
+        let mut state = State::new(candidates[0].to_owned(), word_set);
+        for guess in path.iter() {
+            state
+                .guess(guess, hard)
+                .map_err(|e| eyre!("{:?} is not allowed: {:?}", guess, e))?;
+        }
+
+        let guess = if hard {
+            solver.next_hard_guess(&state)
+        } else {
+            solver.next_guess(&state)
+        };
+        state
+            .check(&guess, hard)
+            .map_err(|e| eyre!("{:?} is not allowed: {:?}", guess, e))?;
+
+        let solves = candidates.contains(&&*guess);
+        if !solves && partition(&guess, &candidates).contains(&candidates.len()) {
+            bail!(
+                "{:?} doesn't tell {} apart, so the solver would never finish",
+                guess,
+                candidates.join(", ")
+            );
+        }
+
+        let mut groups: Vec<(Matches, Vec<&str>)> = Vec::new();
+        for solution in candidates {
+            let matches = diff(&guess, solution);
+            if matches.win() {
+                continue;
+            }
+            match groups.iter_mut().find(|(m, _)| *m == matches) {
+                Some((_, group)) => group.push(solution),
+                None => groups.push((matches, vec![solution])),
+            }
+        }
+        groups.sort_by_key(|&(matches, _)| pattern(matches));
+
+        path.push(guess.clone());
+        let children = groups
+            .into_iter()
+            .map(|(matches, group)| {
+                let child = Self::build_node(solver, word_set, hard, path, group)?;
+                Ok((matches, child))
+            })
+            .collect::<Result<_>>()?;
+        path.pop();
+
+        Ok(Self {
+            guess,
+            solves,
+            children,
+        })
+    }
+
+    /// Counts how many solutions are found with each number of guesses.
+    /// `counts[n]` is the number of solutions found on guess `n`
+    pub fn guess_counts(&self) -> Vec<usize> {
+        let mut counts = Vec::new();
+        self.count_guesses(1, &mut counts);
+        counts
+    }
+
+    fn count_guesses(&self, depth: usize, counts: &mut Vec<usize>) {
+        if counts.len() <= depth {
+            counts.resize(depth + 1, 0);
+        }
+        if self.solves {
+            counts[depth] += 1;
+        }
+        for (_, child) in &self.children {
+            child.count_guesses(depth + 1, counts);
+        }
+    }
+
+    /// The number of solutions this tree can find
+    pub fn solutions(&self) -> usize {
+        self.guess_counts().iter().sum()
+    }
+
+    /// The most guesses needed to find any solution
+    pub fn max_guesses(&self) -> usize {
+        let counts = self.guess_counts();
+        counts.iter().rposition(|&n| n > 0).unwrap_or_default()
+    }
+
+    /// The average number of guesses needed to find a solution
+    pub fn average_guesses(&self) -> f64 {
+        let counts = self.guess_counts();
+        let total: usize = counts.iter().enumerate().map(|(n, c)| n * c).sum();
+        total as f64 / self.solutions() as f64
+    }
+
+    /// Writes the tree as JSON. Each node is an object with the `guess`,
+    /// whether it `solves` the game, and its `children` keyed by the
+    /// matches (see [`Matches::to_code`])
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json).unwrap();
+        json
+    }
+
+    fn write_json(&self, w: &mut String) -> std::fmt::Result {
+        write!(
+            w,
+            r#"{{"guess":"{}","solves":{},"children":{{"#,
+            self.guess, self.solves
+        )?;
+        for (i, (matches, child)) in self.children.iter().enumerate() {
+            if i > 0 {
+                write!(w, ",")?;
+            }
+            write!(w, r#""{}":"#, matches.to_code())?;
+            child.write_json(w)?;
+        }
+        write!(w, "}}}}")
+    }
+
+    /// Writes the tree as a Graphviz DOT graph.
+    /// Guesses that can solve the game are drawn with a double border
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        self.write_dot(&mut dot, &mut 0).unwrap();
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot(&self, w: &mut String, next_id: &mut usize) -> Result<usize, std::fmt::Error> {
+        let id = *next_id;
+        *next_id += 1;
+
+        let peripheries = if self.solves { 2 } else { 1 };
+        writeln!(
+            w,
+            "    n{} [label=\"{}\" peripheries={}];",
+            id, self.guess, peripheries
+        )?;
+        for (matches, child) in &self.children {
+            let child_id = child.write_dot(w, next_id)?;
+            writeln!(
+                w,
+                "    n{} -> n{} [label=\"{}\"];",
+                id,
+                child_id,
+                matches.to_code()
+            )?;
+        }
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecisionTree;
+    use crate::{
+        solver::{Benchmark, Entropy, Minimax, Solver},
+        state::State,
+        words::WordSetBuf,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn tree_exports() {
+        let word_set = WordSetBuf::parse("cigar\nrebut\nhumph", "").unwrap().leak();
+        let tree = DecisionTree::build(&Minimax::with_opener("cigar"), word_set, false).unwrap();
+        assert_eq!(tree.guess_counts(), [0, 1, 2]);
+        assert_eq!(
+            tree.to_json(),
+            r#"{"guess":"cigar","solves":true,"children":{"....y":{"guess":"rebut","solves":true,"children":{}},".....":{"guess":"humph","solves":true,"children":{}}}}"#
+        );
+        assert_eq!(
+            tree.to_dot(),
+            r#"digraph {
+    n0 [label="cigar" peripheries=2];
+    n1 [label="rebut" peripheries=2];
+    n0 -> n1 [label="....y"];
+    n2 [label="humph" peripheries=2];
+    n0 -> n2 [label="....."];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn bench_matches_tree() {
+        let word_set = WordSetBuf::parse("cigar\nrebut\nsissy\nhumph\nawake\nblush", "")
+            .unwrap()
+            .leak();
+        let tree = DecisionTree::build(&Entropy::with_opener("sissy"), word_set, false).unwrap();
+        let bench = Benchmark::run(Arc::new(Entropy::with_opener("sissy")), word_set, false, 3);
+        assert_eq!(bench.guess_counts, tree.guess_counts());
+        assert!(bench.failures.is_empty());
+    }
+
+    /// Always guesses the same word
+    struct Stuck;
+
+    impl Solver for Stuck {
+        fn next_guess(&self, _: &State) -> String {
+            "nymph".to_owned()
+        }
+
+        fn next_hard_guess(&self, state: &State) -> String {
+            self.next_guess(state)
+        }
+    }
+
+    #[test]
+    fn stuck_solver_is_an_error() {
+        let word_set = WordSetBuf::parse("cigar\nrebut", "nymph").unwrap().leak();
+        assert!(DecisionTree::build(&Stuck, word_set, false).is_err());
+    }
+}
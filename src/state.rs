@@ -12,6 +12,8 @@ pub struct State {
 pub enum GuessError {
     MissingExactValues(usize),
     NotInWordList,
+    /// The word is less common than allowed, see [`Game::common_words_only`](crate::game::Game::common_words_only)
+    Uncommon,
 }
 
 pub enum GameOver {
@@ -46,7 +48,7 @@ impl State {
 
     /// Reveal the solution for the current game state
     pub fn solution(&self) -> &str {
-        &*self.solution
+        &self.solution
     }
 
     /// Get the number of maximum possible guesses
@@ -57,40 +59,52 @@ impl State {
     /// Returns an iterator over the previous guesses
     pub fn guesses(&self) -> StateIter<'_> {
         StateIter {
-            solution: &*self.solution(),
+            solution: self.solution(),
             guesses: self.guesses.iter(),
         }
     }
 
-    /// Make a guess.
+    /// Get the [`WordSet`] this game is played with
+    pub fn word_set(&self) -> WordSet<'static> {
+        self.word_set
+    }
+
+    /// Check if a guess would be accepted, without making it.
     ///
     /// # Errors
     /// If the guess is an invalid word, or if it doesn't match the
     /// requirements of hard mode, this function will return an error
-    pub fn guess(&mut self, word: &str, hard: bool) -> Result<Matches, GuessError> {
-        if self.word_set.valid(word) {
-            if hard {
-                if let Some((last_word, matches)) = self.guesses().last() {
-                    for i in 0..5 {
-                        if matches[i] == Match::Exact
-                            && last_word.as_bytes()[i] != word.as_bytes()[i]
-                        {
-                            return Err(GuessError::MissingExactValues(i));
-                        }
+    pub fn check(&self, word: &str, hard: bool) -> Result<(), GuessError> {
+        if !self.word_set.valid(word) {
+            return Err(GuessError::NotInWordList);
+        }
+        if hard {
+            if let Some((last_word, matches)) = self.guesses().next_back() {
+                let letters = last_word.chars().zip(word.chars());
+                for (i, (m, (a, b))) in matches.iter().zip(letters).enumerate() {
+                    if *m == Match::Exact && a != b {
+                        return Err(GuessError::MissingExactValues(i));
                     }
                 }
             }
-
-            self.guesses.push(word.to_owned());
-            Ok(crate::diff(word, &*self.solution))
-        } else {
-            Err(GuessError::NotInWordList)
         }
+        Ok(())
+    }
+
+    /// Make a guess.
+    ///
+    /// # Errors
+    /// If the guess is an invalid word, or if it doesn't match the
+    /// requirements of hard mode, this function will return an error
+    pub fn guess(&mut self, word: &str, hard: bool) -> Result<Matches, GuessError> {
+        self.check(word, hard)?;
+        self.guesses.push(word.to_owned());
+        Ok(crate::diff(word, &self.solution))
     }
 
-    /// Determine if the game is over.
+    /// Determine if the game is over. It never is before the first guess
     pub fn game_over(&self) -> Option<GameOver> {
-        let last = &self.guesses[self.guesses.len() - 1];
+        let last = self.guesses.last()?;
         if last == &self.solution {
             Some(GameOver::Win)
         } else if self.guesses.len() >= 6 {
@@ -100,8 +114,9 @@ impl State {
         }
     }
 
-    /// Displays the score card for this game state to the given [`fmt::Write`].
-    pub fn display_score_card(&self, mut w: impl fmt::Write, hard: bool) -> fmt::Result {
+    /// Displays the score for this game state, such as `4/6`, to the given [`fmt::Write`].
+    /// Hard mode games are marked with a `*`
+    pub fn display_score(&self, mut w: impl fmt::Write, hard: bool) -> fmt::Result {
         let Self {
             solution, guesses, ..
         } = self;
@@ -114,7 +129,12 @@ impl State {
 
         let hard_mode = if hard { "*" } else { "" };
 
-        write!(w, "{score}/6{hard_mode}",)?;
+        write!(w, "{score}/6{hard_mode}",)
+    }
+
+    /// Displays the score card for this game state to the given [`fmt::Write`].
+    pub fn display_score_card(&self, mut w: impl fmt::Write, hard: bool) -> fmt::Result {
+        self.display_score(&mut w, hard)?;
         for g in self.guesses() {
             write!(w, "\n{}", g.1)?;
         }
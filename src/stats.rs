@@ -0,0 +1,283 @@
+//! Player statistics, kept between daily games
+
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use eyre::{ensure, eyre, Result, WrapErr};
+
+use crate::{file, state::State};
+
+/// Statistics for the daily games played with one word set
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub played: usize,
+    pub wins: usize,
+    /// How many days in a row had been won as of [`Stats::last_day`].
+    /// Days skipped since then only break the streak once the next game is recorded
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// `guesses[n]` is the number of games won with `n + 1` guesses
+    pub guesses: [usize; 6],
+    /// The day number of the last game recorded
+    pub last_day: Option<usize>,
+}
+
+impl Stats {
+    /// Records a finished daily game. A streak only continues if the
+    /// game is for the day after the last one recorded, so games
+    /// should be recorded in the order of their days.
+    ///
+    /// Returns `false` without recording anything if the game isn't over,
+    /// or if the day isn't after the last one recorded
+    ///
+    /// ```
+    /// use cl_wordle::{state::State, stats::Stats, words::NYTIMES};
+    /// let mut stats = Stats::default();
+    /// for (day, guesses) in [(0, &["crane", "cigar"][..]), (1, &["rebut"]), (3, &["humph"])] {
+    ///     let mut state = State::new(NYTIMES.get_solution(day).to_owned(), NYTIMES);
+    ///     for guess in guesses {
+    ///         state.guess(guess, false).unwrap();
+    ///     }
+    ///     assert!(stats.record(day, &state));
+    /// }
+    /// assert_eq!((stats.played, stats.wins), (3, 3));
+    /// // day 2 was skipped
+    /// assert_eq!((stats.current_streak, stats.max_streak), (1, 2));
+    /// assert_eq!(stats.guesses, [2, 1, 0, 0, 0, 0]);
+    /// ```
+    pub fn record(&mut self, day: usize, state: &State) -> bool {
+        let game_over = match state.game_over() {
+            Some(game_over) => game_over,
+            None => return false,
+        };
+        if self.last_day.map_or(false, |last| day <= last) {
+            return false;
+        }
+
+        self.played += 1;
+        if game_over.is_win() {
+            self.current_streak = match self.last_day {
+                Some(last) if last + 1 == day => self.current_streak + 1,
+                _ => 1,
+            };
+            self.max_streak = self.max_streak.max(self.current_streak);
+            self.wins += 1;
+            if let Some(count) = self.guesses.get_mut(state.guesses().count() - 1) {
+                *count += 1;
+            }
+        } else {
+            self.current_streak = 0;
+        }
+        self.last_day = Some(day);
+        true
+    }
+
+    /// The percentage of games won, from `0` to `100`
+    pub fn win_percentage(&self) -> f64 {
+        if self.played == 0 {
+            0.0
+        } else {
+            100.0 * self.wins as f64 / self.played as f64
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    /// Displays a summary, with a bar chart of the guess distribution
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Played: {}", self.played)?;
+        writeln!(f, "Win %: {:.0}", self.win_percentage())?;
+        write!(f, "Current streak: {}", self.current_streak)?;
+        match self.last_day {
+            Some(day) => writeln!(f, " (as of day {})", day)?,
+            None => writeln!(f)?,
+        }
+        write!(f, "Max streak: {}", self.max_streak)?;
+
+        let most = self
+            .guesses
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        for (i, &count) in self.guesses.iter().enumerate() {
+            let width = (count * 20 + most - 1) / most;
+            write!(f, "\n{}: {}", i + 1, "#".repeat(width))?;
+            if width > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", count)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`Stats`] for every word set, kept apart for normal and hard mode.
+///
+/// Word sets are named by the caller, such as by their language.
+/// Displays as the text format read by [`StatsBook::parse`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatsBook {
+    entries: BTreeMap<(String, bool), Stats>,
+}
+
+impl StatsBook {
+    /// Parses stats, with one line per word set:
+    /// `<word set> <normal|hard> <played> <wins> <current streak> <max streak> <last day|-> <guesses 1-6>...`
+    ///
+    /// ```
+    /// use cl_wordle::stats::StatsBook;
+    /// let book = StatsBook::parse("en hard 3 2 1 2 41 0 1 1 0 0 0\n").unwrap();
+    /// let stats = book.get("en", true).unwrap();
+    /// assert_eq!((stats.played, stats.wins, stats.last_day), (3, 2, Some(41)));
+    /// assert!(book.get("en", false).is_none());
+    /// assert_eq!(book.to_string(), "en hard 3 2 1 2 41 0 1 1 0 0 0\n");
+    ///
+    /// assert!(StatsBook::parse("en easy 3 2 1 2 - 0 1 1 0 0 0").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// If any line is not in the format above
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut book = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, stats) = parse_line(line).wrap_err_with(|| format!("line {}", i + 1))?;
+            book.entries.insert(key, stats);
+        }
+        Ok(book)
+    }
+
+    /// Loads stats from a file written by [`StatsBook::save`].
+    /// If the file doesn't exist yet, there are no stats
+    ///
+    /// # Errors
+    /// If the file can't be read or parsed
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        file::load(path.as_ref(), Self::parse)
+    }
+
+    /// Saves the stats to a file, creating its directory if needed
+    ///
+    /// # Errors
+    /// If the file can't be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        file::save(path.as_ref(), &self.to_string())
+    }
+
+    /// Gets the stats for a word set
+    pub fn get(&self, word_set: &str, hard: bool) -> Option<&Stats> {
+        self.entries.get(&(word_set.to_owned(), hard))
+    }
+
+    /// Gets the stats for a word set to update, starting them if there are none
+    pub fn entry(&mut self, word_set: &str, hard: bool) -> &mut Stats {
+        self.entries.entry((word_set.to_owned(), hard)).or_default()
+    }
+
+    /// Iterates over the stats of every word set, with the word set and whether it was hard mode
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool, &Stats)> {
+        self.entries
+            .iter()
+            .map(|((word_set, hard), stats)| (word_set.as_str(), *hard, stats))
+    }
+}
+
+fn parse_line(line: &str) -> Result<((String, bool), Stats)> {
+    let mut fields = line.split_whitespace();
+
+    let word_set = field(&mut fields)?.to_owned();
+    let hard = file::parse_mode(field(&mut fields)?)?;
+    let mut stats = Stats {
+        played: number(&mut fields)?,
+        wins: number(&mut fields)?,
+        current_streak: number(&mut fields)?,
+        max_streak: number(&mut fields)?,
+        ..Stats::default()
+    };
+    stats.last_day = match field(&mut fields)? {
+        "-" => None,
+        day => Some(day.parse()?),
+    };
+    for count in &mut stats.guesses {
+        *count = number(&mut fields)?;
+    }
+    ensure!(fields.next().is_none(), "too many fields");
+    Ok(((word_set, hard), stats))
+}
+
+fn field<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Result<&'a str> {
+    fields.next().ok_or_else(|| eyre!("expected more fields"))
+}
+
+fn number<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Result<usize> {
+    Ok(field(fields)?.parse()?)
+}
+
+impl fmt::Display for StatsBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((word_set, hard), stats) in &self.entries {
+            write!(
+                f,
+                "{} {} {} {} {} {} ",
+                word_set,
+                file::mode(*hard),
+                stats.played,
+                stats.wins,
+                stats.current_streak,
+                stats.max_streak,
+            )?;
+            match stats.last_day {
+                Some(day) => write!(f, "{}", day)?,
+                None => write!(f, "-")?,
+            }
+            for count in stats.guesses {
+                write!(f, " {}", count)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use crate::{state::State, words::NYTIMES};
+
+    fn play(day: usize, guesses: &[&str]) -> State {
+        let mut state = State::new(NYTIMES.get_solution(day).to_owned(), NYTIMES);
+        for guess in guesses {
+            state.guess(guess, false).unwrap();
+        }
+        state
+    }
+
+    #[test]
+    fn losing_ends_streak() {
+        let mut stats = Stats::default();
+        assert!(stats.record(0, &play(0, &["cigar"])));
+        let lose = ["crane", "crane", "crane", "crane", "crane", "crane"];
+        assert!(stats.record(1, &play(1, &lose)));
+        assert_eq!((stats.played, stats.wins), (2, 1));
+        assert_eq!((stats.current_streak, stats.max_streak), (0, 1));
+        assert_eq!(stats.guesses, [1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn unfinished_and_repeated_games() {
+        let mut stats = Stats::default();
+        assert!(!stats.record(0, &play(0, &[])));
+        assert!(!stats.record(0, &play(0, &["crane"])));
+        assert!(stats.record(0, &play(0, &["cigar"])));
+        assert!(!stats.record(0, &play(0, &["cigar"])));
+        assert_eq!(stats.played, 1);
+
+        // earlier days would break the streak, and could be counted twice
+        assert!(stats.record(2, &play(2, &["sissy"])));
+        assert!(!stats.record(1, &play(1, &["rebut"])));
+        assert_eq!((stats.played, stats.current_streak), (2, 1));
+    }
+}
@@ -1,12 +1,60 @@
-mod nytimes;
-mod original;
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs,
+    path::Path,
+};
+
+use eyre::{ensure, Result, WrapErr};
+
+mod frequency;
+mod index;
+mod ops;
+mod schedule;
+
+// word lists generated by build.rs from `src/words/<set>/*.txt`
+mod nytimes {
+    include!(concat!(env!("OUT_DIR"), "/nytimes.rs"));
+}
+mod original {
+    include!(concat!(env!("OUT_DIR"), "/original.rs"));
+}
+#[cfg(feature = "lang-de")]
+mod german {
+    include!(concat!(env!("OUT_DIR"), "/german.rs"));
+}
+#[cfg(feature = "lang-es")]
+mod spanish {
+    include!(concat!(env!("OUT_DIR"), "/spanish.rs"));
+}
+#[cfg(feature = "lang-fr")]
+mod french {
+    include!(concat!(env!("OUT_DIR"), "/french.rs"));
+}
+
+pub use frequency::Frequencies;
+pub use index::WordIndex;
+pub use ops::{has_repeated_letters, is_plural};
+pub use schedule::Schedule;
+
+/// The letters used by english word sets
+pub const ENGLISH_ALPHABET: &str = nytimes::ALPHABET;
 
 #[derive(Clone, Copy)]
 pub struct WordSet<'a> {
+    /// Name of the language of this word set, in that language
+    pub language: &'a str,
+    /// All the letters that words in this set can be made from
+    pub alphabet: &'a str,
     #[cfg(feature = "time")]
     pub date_offset: time::Date,
     pub solutions: &'a [&'a str],
     pub acceptable: &'a [&'a str],
+    /// Sorted word lists, used to quickly check if words are valid
+    pub index: WordIndex<'a>,
+    /// Overrides for the solutions of specific days
+    pub schedule: Schedule<'a>,
+    /// How often each word is used, if known
+    pub frequencies: Frequencies<'a>,
 }
 
 impl Default for WordSet<'static> {
@@ -17,33 +65,522 @@ impl Default for WordSet<'static> {
 
 /// Set of the original words
 pub const ORIGINAL: WordSet<'static> = WordSet {
+    language: "English",
+    alphabet: original::ALPHABET,
     #[cfg(feature = "time")]
     date_offset: time::macros::date!(2021 - 06 - 19),
     solutions: original::FINAL,
     acceptable: original::ACCEPT,
+    index: WordIndex::from_sorted(original::SORTED, original::ACCEPT),
+    schedule: Schedule::EMPTY,
+    frequencies: Frequencies::from_sorted(original::FREQUENCY),
 };
 
 pub const NYTIMES: WordSet<'static> = WordSet {
+    language: "English",
+    alphabet: nytimes::ALPHABET,
     #[cfg(feature = "time")]
     date_offset: time::macros::date!(2021 - 06 - 19),
     solutions: nytimes::FINAL,
     acceptable: nytimes::ACCEPT,
+    index: WordIndex::from_sorted(nytimes::SORTED, nytimes::ACCEPT),
+    schedule: Schedule::EMPTY,
+    frequencies: Frequencies::from_sorted(nytimes::FREQUENCY),
+};
+
+/// Set of german words
+#[cfg(feature = "lang-de")]
+pub const GERMAN: WordSet<'static> = WordSet {
+    language: "Deutsch",
+    alphabet: german::ALPHABET,
+    #[cfg(feature = "time")]
+    date_offset: time::macros::date!(2022 - 01 - 07),
+    solutions: german::FINAL,
+    acceptable: german::ACCEPT,
+    index: WordIndex::from_sorted(german::SORTED, german::ACCEPT),
+    schedule: Schedule::EMPTY,
+    frequencies: Frequencies::from_sorted(german::FREQUENCY),
+};
+
+/// Set of spanish words
+#[cfg(feature = "lang-es")]
+pub const SPANISH: WordSet<'static> = WordSet {
+    language: "Español",
+    alphabet: spanish::ALPHABET,
+    #[cfg(feature = "time")]
+    date_offset: time::macros::date!(2022 - 01 - 06),
+    solutions: spanish::FINAL,
+    acceptable: spanish::ACCEPT,
+    index: WordIndex::from_sorted(spanish::SORTED, spanish::ACCEPT),
+    schedule: Schedule::EMPTY,
+    frequencies: Frequencies::from_sorted(spanish::FREQUENCY),
+};
+
+/// Set of french words
+#[cfg(feature = "lang-fr")]
+pub const FRENCH: WordSet<'static> = WordSet {
+    language: "Français",
+    alphabet: french::ALPHABET,
+    #[cfg(feature = "time")]
+    date_offset: time::macros::date!(2022 - 01 - 10),
+    solutions: french::FINAL,
+    acceptable: french::ACCEPT,
+    index: WordIndex::from_sorted(french::SORTED, french::ACCEPT),
+    schedule: Schedule::EMPTY,
+    frequencies: Frequencies::from_sorted(french::FREQUENCY),
 };
 
 impl<'a> WordSet<'a> {
-    /// Gets the solution word for the given day
+    /// Gets the solution word for the given day.
+    /// The [`Schedule`] is checked first, before falling back to the solutions list
     pub fn get_solution(self, day: usize) -> &'a str {
-        self.solutions[day % self.solutions.len()]
+        match self.schedule.get(day) {
+            Some(solution) => solution,
+            None => self.solutions[day % self.solutions.len()],
+        }
     }
 
     /// Gets the current day number from the given date
+    ///
+    /// ```
+    /// use cl_wordle::words::NYTIMES;
+    /// use time::macros::date;
+    ///
+    /// assert_eq!(NYTIMES.get_day(date!(2022 - 02 - 19)).unwrap(), 245);
+    /// assert!(NYTIMES.get_day(date!(2021 - 01 - 01)).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// If the date is before the first day of this word set
     #[cfg(feature = "time")]
-    pub fn get_day(self, date: time::Date) -> usize {
-        (date.to_julian_day() - self.date_offset.to_julian_day()) as usize
+    pub fn get_day(self, date: time::Date) -> Result<usize> {
+        days_since(self.date_offset, date)
+    }
+
+    /// Gets the date of the given day number. The inverse of [`WordSet::get_day`].
+    /// Returns `None` if the date is out of range
+    ///
+    /// ```
+    /// use cl_wordle::words::NYTIMES;
+    /// use time::macros::date;
+    ///
+    /// assert_eq!(NYTIMES.date_for_day(245), Some(date!(2022 - 02 - 19)));
+    /// assert_eq!(NYTIMES.date_for_day(usize::MAX), None);
+    /// ```
+    #[cfg(feature = "time")]
+    pub fn date_for_day(self, day: usize) -> Option<time::Date> {
+        let day = i64::try_from(day).ok()?;
+        self.date_offset.checked_add(time::Duration::days(day))
     }
 
     /// Determines if the given word is valid, according to the default word lists
     pub fn valid(self, word: &str) -> bool {
-        self.solutions.contains(&word) || self.acceptable.contains(&word)
+        self.index.contains(word)
+    }
+
+    /// Determines if the given word is a possible solution
+    pub fn is_solution(self, word: &str) -> bool {
+        self.index.is_solution(word)
     }
+
+    /// A hash of the word lists, which changes whenever a word is added,
+    /// removed or reordered. Useful for caching results worked out from a word set
+    ///
+    /// ```
+    /// use cl_wordle::words::{NYTIMES, ORIGINAL};
+    /// assert_eq!(NYTIMES.version(), NYTIMES.version());
+    /// assert_ne!(NYTIMES.version(), ORIGINAL.version());
+    /// ```
+    pub fn version(self) -> u64 {
+        let mut hash = Fnv1a::default();
+        for list in [self.solutions, self.acceptable] {
+            hash.write_list(list);
+            // an empty line between the lists, so moving a word from one to the other changes the hash
+            hash.write(b"\n");
+        }
+        hash.finish()
+    }
+
+    /// Gets how common the given word is, from `0.0` to `1.0`.
+    ///
+    /// Uses the [`Frequencies`] if there are any. Otherwise the solutions,
+    /// which are picked to be well known words, are all `1.0`
+    /// and every other word is `0.0`.
+    ///
+    /// ```
+    /// use cl_wordle::words::NYTIMES;
+    /// assert_eq!(NYTIMES.commonness("cigar"), 1.0);
+    /// assert_eq!(NYTIMES.commonness("zupas"), 0.0);
+    /// ```
+    pub fn commonness(self, word: &str) -> f64 {
+        if !self.frequencies.is_empty() {
+            self.frequencies.commonness(word)
+        } else if self.is_solution(word) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The FNV-1a hash, which is the same between runs and builds,
+/// unlike the hashers in the standard library
+pub(crate) struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv1a {
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    /// Hashes a word list, with each word followed by a newline
+    pub(crate) fn write_list(&mut self, list: &[&str]) {
+        for word in list {
+            self.write(word.as_bytes());
+            self.write(b"\n");
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// An owned version of a [`WordSet`], such as one loaded from word lists on disk
+#[derive(Clone, Debug)]
+pub struct WordSetBuf {
+    pub language: String,
+    pub alphabet: String,
+    #[cfg(feature = "time")]
+    pub date_offset: time::Date,
+    pub solutions: Vec<String>,
+    pub acceptable: Vec<String>,
+    /// `(day, solution)` overrides, see [`Schedule`]
+    pub schedule: Vec<(usize, String)>,
+    /// `(word, count)` pairs, see [`Frequencies`]
+    pub frequencies: Vec<(String, u64)>,
+}
+
+impl WordSetBuf {
+    /// Parses the solution and acceptable word lists.
+    /// Each list should have one word per line. Empty lines and
+    /// anything after a `#` are ignored. The acceptable list may
+    /// also contain the solutions.
+    ///
+    /// ```
+    /// use cl_wordle::words::WordSetBuf;
+    /// let word_set = WordSetBuf::parse("cigar\nrebut\n", "aahed\naalii\n").unwrap();
+    /// assert_eq!(word_set.solutions, ["cigar", "rebut"]);
+    ///
+    /// assert!(WordSetBuf::parse("cigars", "").is_err());
+    /// assert!(WordSetBuf::parse("Cigar", "").is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// If any word is not made of 5 lowercase letters, or if
+    /// there are no solutions, this function will return an error
+    pub fn parse(solutions: &str, acceptable: &str) -> Result<Self> {
+        let solutions = parse_list(solutions).wrap_err("invalid solutions list")?;
+        let mut acceptable = parse_list(acceptable).wrap_err("invalid acceptable list")?;
+        ensure!(!solutions.is_empty(), "solutions list must not be empty");
+
+        let solution_set: HashSet<&String> = solutions.iter().collect();
+        acceptable.retain(|word| !solution_set.contains(word));
+
+        let alphabet: BTreeSet<char> = solutions
+            .iter()
+            .chain(&acceptable)
+            .flat_map(|w| w.chars())
+            .collect();
+
+        Ok(Self {
+            language: NYTIMES.language.to_owned(),
+            alphabet: alphabet.into_iter().collect(),
+            #[cfg(feature = "time")]
+            date_offset: NYTIMES.date_offset,
+            solutions,
+            acceptable,
+            schedule: Vec::new(),
+            frequencies: Vec::new(),
+        })
+    }
+
+    /// Loads the solution and acceptable word lists from the given files.
+    /// See [`WordSetBuf::parse`] for the expected format
+    pub fn load(solutions: impl AsRef<Path>, acceptable: impl AsRef<Path>) -> Result<Self> {
+        let solutions = read_list(solutions.as_ref())?;
+        let acceptable = read_list(acceptable.as_ref())?;
+        Self::parse(&solutions, &acceptable)
+    }
+
+    /// Parses a schedule of solution overrides, adding them to this word set.
+    ///
+    /// Each line should contain a day number, or a `year-month-day` date,
+    /// followed by the solution for that day. Empty lines and
+    /// anything after a `#` are ignored.
+    ///
+    /// ```
+    /// use cl_wordle::words::{WordSetBuf, NYTIMES};
+    /// let mut word_set = WordSetBuf::from(NYTIMES);
+    /// word_set.parse_schedule("
+    ///     0 rebut # swapped with day 1
+    ///     2021-06-20 cigar
+    /// ").unwrap();
+    ///
+    /// let word_set = word_set.leak();
+    /// assert_eq!(word_set.get_solution(0), "rebut");
+    /// assert_eq!(word_set.get_solution(1), "cigar");
+    /// assert_eq!(word_set.get_solution(2), "sissy");
+    /// ```
+    ///
+    /// # Errors
+    /// If any line is malformed, if a day is scheduled twice, or if a
+    /// scheduled solution is not in the word lists, this function will return an error
+    pub fn parse_schedule(&mut self, schedule: &str) -> Result<()> {
+        for (i, line) in schedule.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut parts = line.split_whitespace();
+            let (when, word) = match (parts.next(), parts.next(), parts.next()) {
+                (None, ..) => continue,
+                (Some(when), Some(word), None) => (when, word),
+                _ => eyre::bail!("line {}: expected a day or date, followed by a word", i + 1),
+            };
+
+            let day = self
+                .parse_day(when)
+                .wrap_err_with(|| format!("line {}", i + 1))?;
+            ensure!(
+                self.solutions
+                    .iter()
+                    .chain(&self.acceptable)
+                    .any(|w| w == word),
+                "line {}: {:?} is not in the word lists",
+                i + 1,
+                word
+            );
+            ensure!(
+                self.schedule.iter().all(|&(d, _)| d != day),
+                "line {}: day {} is already scheduled",
+                i + 1,
+                day
+            );
+            self.schedule.push((day, word.to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Loads a schedule of solution overrides from the given file, adding them to this word set.
+    /// See [`WordSetBuf::parse_schedule`] for the expected format
+    pub fn load_schedule(&mut self, schedule: impl AsRef<Path>) -> Result<()> {
+        let schedule = read_list(schedule.as_ref())?;
+        self.parse_schedule(&schedule)
+    }
+
+    /// Parses a list of word counts, adding them to this word set.
+    ///
+    /// Each line should contain a word followed by how many times it is used,
+    /// such as in a large body of text. Words that aren't in the word lists
+    /// are skipped, so general purpose frequency lists can be used.
+    /// Empty lines and anything after a `#` are ignored.
+    ///
+    /// ```
+    /// use cl_wordle::words::{WordSetBuf, NYTIMES};
+    /// let mut word_set = WordSetBuf::from(NYTIMES);
+    /// word_set.parse_frequencies("
+    ///     about 1000000
+    ///     cigar 5000
+    ///     zupas 1
+    ///     the 20000000 # not a 5 letter word
+    /// ").unwrap();
+    ///
+    /// let word_set = word_set.leak();
+    /// assert_eq!(word_set.frequencies.get("cigar"), 5000);
+    /// assert!(word_set.commonness("about") > word_set.commonness("zupas"));
+    /// assert_eq!(word_set.commonness("crane"), 0.0);
+    /// ```
+    ///
+    /// # Errors
+    /// If any line is malformed, or if a word is listed twice,
+    /// this function will return an error
+    pub fn parse_frequencies(&mut self, frequencies: &str) -> Result<()> {
+        let words: HashSet<&str> = self
+            .solutions
+            .iter()
+            .chain(&self.acceptable)
+            .map(|w| &**w)
+            .collect();
+        let mut seen: HashSet<String> = self.frequencies.iter().map(|(w, _)| w.clone()).collect();
+
+        let mut added = Vec::new();
+        for (i, line) in frequencies.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut parts = line.split_whitespace();
+            let (word, count) = match (parts.next(), parts.next(), parts.next()) {
+                (None, ..) => continue,
+                (Some(word), Some(count), None) => (word, count),
+                _ => eyre::bail!("line {}: expected a word, followed by a count", i + 1),
+            };
+
+            let count = count
+                .parse()
+                .wrap_err_with(|| format!("line {}: {:?} is not a valid count", i + 1, count))?;
+            if !words.contains(word) {
+                continue;
+            }
+            ensure!(
+                seen.insert(word.to_owned()),
+                "line {}: {:?} is already listed",
+                i + 1,
+                word
+            );
+            added.push((word.to_owned(), count));
+        }
+        self.frequencies.extend(added);
+        Ok(())
+    }
+
+    /// Loads a list of word counts from the given file, adding them to this word set.
+    /// See [`WordSetBuf::parse_frequencies`] for the expected format
+    pub fn load_frequencies(&mut self, frequencies: impl AsRef<Path>) -> Result<()> {
+        let frequencies = read_list(frequencies.as_ref())?;
+        self.parse_frequencies(&frequencies)
+    }
+
+    fn parse_day(&self, when: &str) -> Result<usize> {
+        if let Ok(day) = when.parse() {
+            return Ok(day);
+        }
+
+        #[cfg(feature = "time")]
+        {
+            let description =
+                time::macros::format_description!("[year]-[month padding:none]-[day padding:none]");
+            if let Ok(date) = time::Date::parse(when, description) {
+                return days_since(self.date_offset, date);
+            }
+        }
+
+        eyre::bail!("{:?} is not a valid day", when)
+    }
+
+    /// Converts this into a [`WordSet`] that lives for the rest of the program,
+    /// so it can be used anywhere the bundled word sets can.
+    ///
+    /// The word lists are leaked, so this should only be called
+    /// a small number of times.
+    ///
+    /// # Panics
+    /// If there are no solutions
+    pub fn leak(mut self) -> WordSet<'static> {
+        assert!(!self.solutions.is_empty(), "word set has no solutions");
+
+        fn leak_list(list: Vec<String>) -> &'static [&'static str] {
+            let list: Vec<&'static str> = list
+                .into_iter()
+                .map(|word| &*Box::leak(word.into_boxed_str()))
+                .collect();
+            Box::leak(list.into_boxed_slice())
+        }
+
+        let mut sorted_solutions = self.solutions.clone();
+        sorted_solutions.sort_unstable();
+        self.acceptable.sort_unstable();
+
+        self.schedule.sort_unstable_by_key(|&(day, _)| day);
+        let schedule: Vec<(usize, &'static str)> = self
+            .schedule
+            .into_iter()
+            .map(|(day, word)| (day, &*Box::leak(word.into_boxed_str())))
+            .collect();
+
+        self.frequencies.sort_unstable();
+        let frequencies: Vec<(&'static str, u64)> = self
+            .frequencies
+            .into_iter()
+            .map(|(word, count)| (&*Box::leak(word.into_boxed_str()), count))
+            .collect();
+
+        let acceptable = leak_list(self.acceptable);
+        WordSet {
+            language: Box::leak(self.language.into_boxed_str()),
+            alphabet: Box::leak(self.alphabet.into_boxed_str()),
+            #[cfg(feature = "time")]
+            date_offset: self.date_offset,
+            solutions: leak_list(self.solutions),
+            acceptable,
+            index: WordIndex::from_sorted(leak_list(sorted_solutions), acceptable),
+            schedule: Schedule::from_sorted(Box::leak(schedule.into_boxed_slice())),
+            frequencies: Frequencies::from_sorted(Box::leak(frequencies.into_boxed_slice())),
+        }
+    }
+}
+
+impl<'a> From<WordSet<'a>> for WordSetBuf {
+    fn from(word_set: WordSet<'a>) -> Self {
+        fn to_owned(list: &[&str]) -> Vec<String> {
+            list.iter().map(|&word| word.to_owned()).collect()
+        }
+
+        Self {
+            language: word_set.language.to_owned(),
+            alphabet: word_set.alphabet.to_owned(),
+            #[cfg(feature = "time")]
+            date_offset: word_set.date_offset,
+            solutions: to_owned(word_set.solutions),
+            acceptable: to_owned(word_set.acceptable),
+            schedule: word_set
+                .schedule
+                .entries()
+                .iter()
+                .map(|&(day, word)| (day, word.to_owned()))
+                .collect(),
+            frequencies: word_set
+                .frequencies
+                .entries()
+                .iter()
+                .map(|&(word, count)| (word.to_owned(), count))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+fn days_since(date_offset: time::Date, date: time::Date) -> Result<usize> {
+    let day = date.to_julian_day() - date_offset.to_julian_day();
+    ensure!(
+        day >= 0,
+        "{} is before the first day ({})",
+        date,
+        date_offset
+    );
+    Ok(day as usize)
+}
+
+fn read_list(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("could not read {}", path.display()))
+}
+
+fn parse_list(list: &str) -> Result<Vec<String>> {
+    list.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.split('#').next().unwrap_or_default().trim()))
+        .filter(|(_, word)| !word.is_empty())
+        .map(|(line, word)| {
+            ensure!(
+                word.chars().count() == 5 && word.chars().all(char::is_lowercase),
+                "line {}: {:?} is not 5 lowercase letters",
+                line,
+                word
+            );
+            Ok(word.to_owned())
+        })
+        .collect()
 }
@@ -0,0 +1,86 @@
+use super::index::less;
+
+/// How often words are used, such as counts from a large body of text.
+///
+/// Word lists are full of obscure entries like `zoeae` and `zupas`,
+/// so these can be used to prefer (or require) well known words.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Frequencies<'a> {
+    entries: &'a [(&'a str, u64)],
+    max: u64,
+}
+
+impl<'a> Frequencies<'a> {
+    /// No frequency information
+    pub const EMPTY: Self = Frequencies {
+        entries: &[],
+        max: 0,
+    };
+
+    /// Create a new table from a list of `(word, count)` pairs, sorted by word
+    ///
+    /// # Panics
+    /// If the list is not sorted by word, or if any word is repeated.
+    /// When used to create a constant, this will fail to compile instead.
+    pub const fn from_sorted(entries: &'a [(&'a str, u64)]) -> Self {
+        let mut max = 0;
+        let mut i = 0;
+        while i < entries.len() {
+            assert!(
+                i == 0 || less(entries[i - 1].0, entries[i].0),
+                "frequencies must be sorted by word"
+            );
+            if entries[i].1 > max {
+                max = entries[i].1;
+            }
+            i += 1;
+        }
+        Self { entries, max }
+    }
+
+    /// Determines if there is no frequency information
+    pub fn is_empty(self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the count for the given word. Words that aren't listed have a count of 0
+    pub fn get(self, word: &str) -> u64 {
+        match self.entries.binary_search_by_key(&word, |&(word, _)| word) {
+            Ok(i) => self.entries[i].1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Gets how common the given word is, from `0.0` (never used) to `1.0` (the most used word).
+    /// The counts are compared on a log scale, since word counts vary so widely
+    pub fn commonness(self, word: &str) -> f64 {
+        if self.max == 0 {
+            return 0.0;
+        }
+        (self.get(word) as f64).ln_1p() / (self.max as f64).ln_1p()
+    }
+
+    /// The `(word, count)` pairs in this table
+    pub fn entries(self) -> &'a [(&'a str, u64)] {
+        self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Frequencies;
+
+    #[test]
+    fn commonness() {
+        let frequencies = Frequencies::from_sorted(&[("about", 1000), ("zoeae", 1)]);
+        assert_eq!(frequencies.commonness("about"), 1.0);
+        assert!(frequencies.commonness("zoeae") < 0.2);
+        assert_eq!(frequencies.commonness("zupas"), 0.0);
+    }
+
+    #[test]
+    #[should_panic = "frequencies must be sorted by word"]
+    fn unsorted() {
+        Frequencies::from_sorted(&[("zoeae", 1), ("about", 1000)]);
+    }
+}
@@ -0,0 +1,78 @@
+/// Sorted copies of the word lists in a [`WordSet`](super::WordSet),
+/// so that words can be looked up with a binary search
+#[derive(Clone, Copy, Debug)]
+pub struct WordIndex<'a> {
+    solutions: &'a [&'a str],
+    acceptable: &'a [&'a str],
+}
+
+impl<'a> WordIndex<'a> {
+    /// Create a new index from the given sorted word lists
+    ///
+    /// # Panics
+    /// If either list is not sorted. When used to create a constant,
+    /// this will fail to compile instead.
+    pub const fn from_sorted(solutions: &'a [&'a str], acceptable: &'a [&'a str]) -> Self {
+        assert!(is_sorted(solutions), "solutions index must be sorted");
+        assert!(is_sorted(acceptable), "acceptable index must be sorted");
+        Self {
+            solutions,
+            acceptable,
+        }
+    }
+
+    /// Determines if the given word is in the solutions list
+    pub fn is_solution(self, word: &str) -> bool {
+        self.solutions.binary_search(&word).is_ok()
+    }
+
+    /// Determines if the given word is in either word list
+    pub fn contains(self, word: &str) -> bool {
+        self.is_solution(word) || self.acceptable.binary_search(&word).is_ok()
+    }
+}
+
+const fn is_sorted(words: &[&str]) -> bool {
+    let mut i = 1;
+    while i < words.len() {
+        if less(words[i], words[i - 1]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// const equivalent of `a < b`
+pub(super) const fn less(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WordIndex;
+
+    #[test]
+    fn sorted_index() {
+        let index =
+            WordIndex::from_sorted(&["awake", "cigar", "rebut", "sissy"], &["aahed", "zymic"]);
+        assert!(index.is_solution("cigar"));
+        assert!(!index.is_solution("zymic"));
+        assert!(index.contains("zymic"));
+        assert!(!index.contains("crane"));
+    }
+
+    #[test]
+    #[should_panic = "solutions index must be sorted"]
+    fn unsorted_index() {
+        WordIndex::from_sorted(&["rebut", "cigar"], &[]);
+    }
+}
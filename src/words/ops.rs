@@ -0,0 +1,145 @@
+//! Combinators for building new word sets out of existing ones
+
+use std::collections::HashSet;
+
+use super::{WordSet, WordSetBuf};
+
+impl WordSetBuf {
+    /// Adds the words from another word set. Solutions from `other`
+    /// are added to the end of the solutions list.
+    ///
+    /// ```
+    /// use cl_wordle::words::{WordSetBuf, NYTIMES, ORIGINAL};
+    /// let word_set = WordSetBuf::from(NYTIMES).union(ORIGINAL);
+    /// assert!(word_set.solutions.iter().any(|w| w == "agora"));
+    /// ```
+    pub fn union(mut self, other: WordSet<'_>) -> Self {
+        let mut seen: HashSet<String> = self.solutions.iter().cloned().collect();
+        for &word in other.solutions {
+            if seen.insert(word.to_owned()) {
+                self.solutions.push(word.to_owned());
+            }
+        }
+        self.acceptable.retain(|word| !seen.contains(word));
+
+        seen.extend(self.acceptable.iter().cloned());
+        for &word in other.acceptable {
+            if seen.insert(word.to_owned()) {
+                self.acceptable.push(word.to_owned());
+            }
+        }
+
+        let counted: HashSet<String> = self.frequencies.iter().map(|(w, _)| w.clone()).collect();
+        for &(word, count) in other.frequencies.entries() {
+            if !counted.contains(word) {
+                self.frequencies.push((word.to_owned(), count));
+            }
+        }
+
+        for c in other.alphabet.chars() {
+            if !self.alphabet.contains(c) {
+                self.alphabet.push(c);
+            }
+        }
+        self
+    }
+
+    /// Removes the solutions of every day up to and including `day`.
+    /// They can still be used as guesses.
+    ///
+    /// ```
+    /// use cl_wordle::words::{WordSetBuf, NYTIMES};
+    /// let word_set = WordSetBuf::from(NYTIMES).without_past_solutions(1);
+    /// assert_eq!(word_set.solutions[0], "sissy");
+    /// assert!(word_set.acceptable.iter().any(|w| w == "cigar"));
+    ///
+    /// // an earlier combinator may have removed every solution
+    /// let word_set = WordSetBuf::from(NYTIMES)
+    ///     .retain_solutions(|_| false)
+    ///     .without_past_solutions(0);
+    /// assert!(word_set.solutions.is_empty());
+    /// ```
+    pub fn without_past_solutions(self, day: usize) -> Self {
+        if self.solutions.is_empty() {
+            return self;
+        }
+
+        let mut played: HashSet<String> = self
+            .schedule
+            .iter()
+            .filter(|&&(d, _)| d <= day)
+            .map(|(_, word)| word.clone())
+            .collect();
+
+        // after this many days, every solution must have been played
+        let last = day.min((self.schedule.len() + 1) * self.solutions.len());
+        for d in 0..=last {
+            if self.schedule.iter().all(|&(s, _)| s != d) {
+                played.insert(self.solutions[d % self.solutions.len()].clone());
+            }
+        }
+
+        self.retain_solutions(|word| !played.contains(word))
+    }
+
+    /// Keeps only the solutions that match the predicate.
+    /// Solutions that are removed can still be used as guesses.
+    ///
+    /// ```
+    /// use cl_wordle::words::{self, WordSetBuf, NYTIMES};
+    /// let word_set = WordSetBuf::from(NYTIMES).retain_solutions(|w| !words::has_repeated_letters(w));
+    /// assert_eq!(word_set.solutions[0], "cigar");
+    /// assert_eq!(word_set.solutions[1], "rebut");
+    /// assert_eq!(word_set.solutions[2], "blush");
+    /// ```
+    pub fn retain_solutions(mut self, mut f: impl FnMut(&str) -> bool) -> Self {
+        let (kept, removed): (Vec<_>, Vec<_>) =
+            self.solutions.into_iter().partition(|word| f(word));
+        self.solutions = kept;
+        self.acceptable.extend(removed);
+        self
+    }
+
+    /// Keeps only the solutions that are in the given tier.
+    /// Solutions that are removed can still be used as guesses.
+    ///
+    /// ```
+    /// use cl_wordle::words::{WordSetBuf, NYTIMES};
+    /// let word_set = WordSetBuf::from(NYTIMES).restrict_solutions(["sissy", "cigar", "zupas"]);
+    /// assert_eq!(word_set.solutions, ["cigar", "sissy"]);
+    /// assert!(word_set.acceptable.iter().any(|w| w == "rebut"));
+    /// ```
+    pub fn restrict_solutions<'a>(self, tier: impl IntoIterator<Item = &'a str>) -> Self {
+        let tier: HashSet<&str> = tier.into_iter().collect();
+        self.retain_solutions(|word| tier.contains(word))
+    }
+
+    /// Keeps only the words that match the predicate.
+    /// Words that are removed can no longer be used as guesses.
+    ///
+    /// ```
+    /// use cl_wordle::words::{self, WordSetBuf, NYTIMES};
+    /// let word_set = WordSetBuf::from(NYTIMES).retain(|w| !words::is_plural(w));
+    /// assert!(word_set.acceptable.iter().all(|w| w != "cares"));
+    /// assert!(word_set.solutions.iter().any(|w| w == "abyss"));
+    /// ```
+    pub fn retain(mut self, mut f: impl FnMut(&str) -> bool) -> Self {
+        self.solutions.retain(|word| f(word));
+        self.acceptable.retain(|word| f(word));
+        self.schedule.retain(|(_, word)| f(word));
+        self.frequencies.retain(|(word, _)| f(word));
+        self
+    }
+}
+
+/// Guesses if a word is a plural, by checking if it ends in an `s`.
+/// Words ending in `ss`, `us` or `is` (eg `abyss`, `focus` and `basis`) are not counted.
+pub fn is_plural(word: &str) -> bool {
+    word.ends_with('s') && !(word.ends_with("ss") || word.ends_with("us") || word.ends_with("is"))
+}
+
+/// Determines if a word uses any letter more than once
+pub fn has_repeated_letters(word: &str) -> bool {
+    word.char_indices()
+        .any(|(i, c)| word[i + c.len_utf8()..].contains(c))
+}
@@ -0,0 +1,39 @@
+/// Overrides for the solutions of specific days.
+///
+/// The official game has changed or reordered some answers,
+/// so the solution list alone can drift from the real game.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Schedule<'a>(&'a [(usize, &'a str)]);
+
+impl<'a> Schedule<'a> {
+    /// A schedule with no overrides
+    pub const EMPTY: Self = Schedule(&[]);
+
+    /// Create a new schedule from a list of `(day, solution)` pairs, sorted by day
+    ///
+    /// # Panics
+    /// If the list is not sorted by day, or if any day is repeated.
+    /// When used to create a constant, this will fail to compile instead.
+    pub const fn from_sorted(entries: &'a [(usize, &'a str)]) -> Self {
+        let mut i = 1;
+        while i < entries.len() {
+            assert!(
+                entries[i - 1].0 < entries[i].0,
+                "schedule must be sorted by day"
+            );
+            i += 1;
+        }
+        Self(entries)
+    }
+
+    /// Gets the scheduled solution for the given day, if there is one
+    pub fn get(self, day: usize) -> Option<&'a str> {
+        let i = self.0.binary_search_by_key(&day, |&(day, _)| day).ok()?;
+        Some(self.0[i].1)
+    }
+
+    /// The `(day, solution)` pairs in this schedule
+    pub fn entries(self) -> &'a [(usize, &'a str)] {
+        self.0
+    }
+}